@@ -0,0 +1,40 @@
+use surrealdb::sql::statements::BreakStatement;
+
+use super::StmtBridge;
+use crate::impl_stmt_bridge;
+
+/// ## create BREAK statement
+/// The BREAK statement can be used to break out of a FOR loop
+/// ### example
+/// ```
+/// let stmt = BreakStmt::new();
+/// assert_eq!(stmt.to_string().as_str(), "BREAK");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BreakStmt {
+    origin: BreakStatement,
+}
+
+impl BreakStmt {
+    pub fn new() -> Self {
+        BreakStmt::default()
+    }
+}
+
+impl ToString for BreakStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(BreakStmt, BreakStatement, Break);
+
+#[cfg(test)]
+mod test_break_stmt {
+    use super::BreakStmt;
+
+    #[test]
+    fn simple() {
+        assert_eq!(BreakStmt::new().to_string().as_str(), "BREAK");
+    }
+}
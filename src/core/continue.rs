@@ -0,0 +1,40 @@
+use surrealdb::sql::statements::ContinueStatement;
+
+use super::StmtBridge;
+use crate::impl_stmt_bridge;
+
+/// ## create CONTINUE statement
+/// The CONTINUE statement can be used to skip to the next iteration of a FOR loop
+/// ### example
+/// ```
+/// let stmt = ContinueStmt::new();
+/// assert_eq!(stmt.to_string().as_str(), "CONTINUE");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContinueStmt {
+    origin: ContinueStatement,
+}
+
+impl ContinueStmt {
+    pub fn new() -> Self {
+        ContinueStmt::default()
+    }
+}
+
+impl ToString for ContinueStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(ContinueStmt, ContinueStatement, Continue);
+
+#[cfg(test)]
+mod test_continue_stmt {
+    use super::ContinueStmt;
+
+    #[test]
+    fn simple() {
+        assert_eq!(ContinueStmt::new().to_string().as_str(), "CONTINUE");
+    }
+}
@@ -67,7 +67,7 @@ impl ToString for CreateStmt {
     }
 }
 
-impl_stmt_bridge!(CreateStmt, CreateStatement);
+impl_stmt_bridge!(CreateStmt, CreateStatement, Create);
 
 #[cfg(test)]
 mod test_create_stmt {
@@ -0,0 +1,134 @@
+use serde_json::Value;
+use surrealdb::sql::{self, statements::DefineAnalyzerStatement};
+
+use crate::core::{
+    sql::{Filter, Tokenizer},
+    StmtBridge,
+};
+use crate::impl_stmt_bridge;
+
+/// ## create DEFINE ANALYZER statement
+/// In the context of a database, an analyzer plays a crucial role in text processing and searching.
+/// It is defined by its name, a set of tokenizers, and a collection of filters,
+/// and can be used by the SEARCH index: `DefineIndexStmt::search(@analyzer)`
+/// ```
+/// DEFINE ANALYZER @name [ TOKENIZERS @tokenizers ] [ FILTERS @filters ]
+/// ```
+/// ### example
+/// ```
+/// let define = DefineAnalyzerStmt::new("example_ascii")
+///     .tokenizers(vec![Tokenizer::Class])
+///     .filters(vec![Filter::Ascii, Filter::Snowball(Language::English)]);
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE ANALYZER example_ascii TOKENIZERS CLASS FILTERS ASCII,SNOWBALL(ENGLISH)"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineAnalyzerStmt {
+    origin: DefineAnalyzerStatement,
+}
+
+impl DefineAnalyzerStmt {
+    /// ## create DEFINE ANALYZER statement
+    /// - name : name of the analyzer, which can be used in `DefineIndexStmt::search`
+    pub fn new(name: &str) -> Self {
+        DefineAnalyzerStmt {
+            origin: DefineAnalyzerStatement {
+                name: name.into(),
+                ..Default::default()
+            },
+        }
+    }
+    /// ## set TOKENIZERS
+    pub fn tokenizers(mut self, tokenizers: Vec<Tokenizer>) -> Self {
+        self.origin.tokenizers.replace(
+            tokenizers
+                .into_iter()
+                .map(sql::Tokenizer::from)
+                .collect::<Vec<sql::Tokenizer>>(),
+        );
+        self
+    }
+    /// ## set FILTERS
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        let filters = Value::Array(filters.into_iter().map(Value::from).collect());
+        // The filter is not exported by surrealdb, so it is built from its serialized form
+        match serde_json::from_value(filters) {
+            Ok(filters) => self.origin.filters.replace(filters),
+            Err(e) => panic!("{}", e),
+        };
+        self
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+    /// name of the analyzer
+    pub fn name(&self) -> &str {
+        &self.origin.name
+    }
+}
+
+impl ToString for DefineAnalyzerStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineAnalyzerStmt,
+    DefineAnalyzerStatement,
+    Define(DefineStatement::Analyzer)
+);
+
+#[cfg(test)]
+mod test_define_analyzer_stmt {
+    use crate::core::{
+        define::DefineIndexStmt,
+        sql::{Field, Filter, Language, Tokenizer},
+    };
+
+    use super::DefineAnalyzerStmt;
+
+    #[test]
+    fn simple() {
+        let define = DefineAnalyzerStmt::new("example_blank").tokenizers(vec![Tokenizer::Blank]);
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE ANALYZER example_blank TOKENIZERS BLANK"
+        );
+    }
+
+    #[test]
+    fn filters() {
+        let define = DefineAnalyzerStmt::new("autocomplete")
+            .tokenizers(vec![Tokenizer::Blank, Tokenizer::Camel, Tokenizer::Punct])
+            .filters(vec![
+                Filter::Lowercase,
+                Filter::Uppercase,
+                Filter::EdgeNgram(2, 10),
+                Filter::Ngram(1, 3),
+                Filter::Snowball(Language::German),
+            ])
+            .comment("autocomplete");
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE ANALYZER autocomplete TOKENIZERS BLANK,CAMEL,PUNCT FILTERS LOWERCASE,UPPERCASE,EDGENGRAM(2,10),NGRAM(1,3),SNOWBALL(GERMAN) COMMENT 'autocomplete'"
+        );
+    }
+
+    #[test]
+    fn with_search_index() {
+        let analyzer = DefineAnalyzerStmt::new("ascii")
+            .tokenizers(vec![Tokenizer::Class])
+            .filters(vec![Filter::Ascii]);
+        let index = DefineIndexStmt::new("userNameIndex", "user".into())
+            .fields(vec![Field::new("name")])
+            .search(analyzer.name());
+        assert_eq!(
+            index.to_string().as_str(),
+            "DEFINE INDEX userNameIndex ON user FIELDS name SEARCH ANALYZER ascii BM25(1.2,0.75) DOC_IDS_ORDER 100 DOC_LENGTHS_ORDER 100 POSTINGS_ORDER 100 TERMS_ORDER 100"
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use surrealdb::sql::{statements::DefineDatabaseStatement, ChangeFeed, Duration};
+
+use crate::core::StmtBridge;
+use crate::impl_stmt_bridge;
+
+/// ## create DEFINE DATABASE statement
+/// The DEFINE DATABASE statement allows you to instantiate a named database,
+/// enabling you to specify security and configuration options.
+/// ```
+/// DEFINE DATABASE @name [ CHANGEFEED @duration ]
+/// ```
+/// ### example
+/// ```
+/// let define = DefineDatabaseStmt::new("app_vitalsense").changefeed(Duration::from_days(7));
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE DATABASE app_vitalsense CHANGEFEED 1w"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineDatabaseStmt {
+    origin: DefineDatabaseStatement,
+}
+
+impl DefineDatabaseStmt {
+    pub fn new(name: &str) -> Self {
+        DefineDatabaseStmt {
+            origin: DefineDatabaseStatement {
+                name: name.into(),
+                ..Default::default()
+            },
+        }
+    }
+    /// ## set CHANGEFEED
+    /// how long the changes of the database will be kept
+    pub fn changefeed(mut self, expiry: Duration) -> Self {
+        self.origin
+            .changefeed
+            .replace(ChangeFeed { expiry: expiry.0 });
+        self
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+    /// name of the database
+    pub fn name(&self) -> &str {
+        &self.origin.name
+    }
+}
+
+impl ToString for DefineDatabaseStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineDatabaseStmt,
+    DefineDatabaseStatement,
+    Define(DefineStatement::Database)
+);
+
+#[cfg(test)]
+mod test_define_database_stmt {
+    use surrealdb::sql::Duration;
+
+    use super::DefineDatabaseStmt;
+
+    #[test]
+    fn simple() {
+        let define = DefineDatabaseStmt::new("app_vitalsense");
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE DATABASE app_vitalsense"
+        );
+    }
+
+    #[test]
+    fn changefeed() {
+        let define = DefineDatabaseStmt::new("app_vitalsense")
+            .changefeed(Duration::from_days(7))
+            .comment("tenant");
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE DATABASE app_vitalsense COMMENT 'tenant' CHANGEFEED 1w"
+        );
+    }
+}
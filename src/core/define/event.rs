@@ -0,0 +1,181 @@
+use surrealdb::sql::{statements::DefineEventStatement, Table, Value};
+
+use crate::core::{
+    sql::{Cond, SurrealTable},
+    StmtBridge, SubqueryStmt,
+};
+use crate::impl_stmt_bridge;
+
+/// ## create DEFINE EVENT statement
+/// Events allow you to define custom logic that is executed when a record is created, updated, or deleted.
+/// These events are triggered automatically within the current transaction after data modifications in the record,
+/// giving you access to the state of the record before `$before` and after `$after` the change.
+/// ```
+/// DEFINE EVENT @name ON [ TABLE ] @table [ WHEN @expression ] THEN @expression
+/// ```
+/// ### example
+/// ```
+/// let define = DefineEventStmt::new("email", "user".into())
+///     .when(
+///         Cond::new()
+///             .left(EventParam::Before.field("email"))
+///             .op(Operator::NotEqual)
+///             .right(EventParam::After.field("email").into()),
+///     )
+///     .then(
+///         Stmt::create().table("event".into()).data(
+///             CreateData::set()
+///                 .push(SetField::new("user", None, Value::from(Field::new("id"))))
+///                 .push(SetField::new("value", None, Value::from(EventParam::After.field("email"))))
+///                 .push(SetField::new("action", None, EventParam::Event)),
+///         ),
+///     );
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE EVENT email ON user WHEN $before.email != $after.email THEN (CREATE event SET user = id, value = $after.email, action = $event)"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineEventStmt {
+    origin: DefineEventStatement,
+}
+
+impl DefineEventStmt {
+    /// ## create DEFINE EVENT statement
+    /// - name : name of the event
+    /// - table : which table the event belongs to, only normal table can be used
+    pub fn new(name: &str, table: SurrealTable) -> Self {
+        DefineEventStmt {
+            origin: DefineEventStatement {
+                name: name.into(),
+                what: Table::from(table).0.into(),
+                when: Value::Bool(true),
+                ..Default::default()
+            },
+        }
+    }
+    /// ## set WHEN
+    /// when not set, the event will be triggered on every change: `WHEN true`
+    pub fn when(mut self, cond: Cond) -> Self {
+        self.origin.when = cond.to_origin().0;
+        self
+    }
+    /// ## push a statement into THEN
+    /// any statement which implement SubqueryStmt (CREATE, UPDATE, RELATE ...)
+    pub fn then<S>(mut self, stmt: S) -> Self
+    where
+        S: SubqueryStmt,
+    {
+        self.origin.then.0.push(stmt.into());
+        self
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+}
+
+impl ToString for DefineEventStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineEventStmt,
+    DefineEventStatement,
+    Define(DefineStatement::Event)
+);
+
+#[cfg(test)]
+mod test_define_event_stmt {
+    use surrealdb::sql::{Operator, Value};
+
+    use crate::core::{
+        define::DefineTableStmt,
+        sql::{Cond, CreateData, EventParam, Field, SetField, UpdateData},
+        Stmt,
+    };
+
+    use super::DefineEventStmt;
+
+    #[test]
+    fn simple() {
+        let define = DefineEventStmt::new("email", "user".into())
+            .when(
+                Cond::new()
+                    .left(EventParam::Before.field("email"))
+                    .op(Operator::NotEqual)
+                    .right(EventParam::After.field("email").into()),
+            )
+            .then(
+                Stmt::create().table("event".into()).data(
+                    CreateData::set()
+                        .push(SetField::new("user", None, Value::from(Field::new("id"))))
+                        .push(SetField::new(
+                            "value",
+                            None,
+                            Value::from(EventParam::After.field("email")),
+                        ))
+                        .push(SetField::new("action", None, EventParam::Event)),
+                ),
+            );
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE EVENT email ON user WHEN $before.email != $after.email THEN (CREATE event SET user = id, value = $after.email, action = $event)"
+        );
+    }
+
+    #[test]
+    fn multiple_then() {
+        let define = DefineEventStmt::new("publish", "post".into())
+            .when(
+                Cond::new()
+                    .left_value(EventParam::Event.into())
+                    .op(Operator::Equal)
+                    .right("CREATE".into()),
+            )
+            .then(
+                Stmt::update()
+                    .table(("stats", "post").into())
+                    .data(UpdateData::set().push(SetField::new("count", Some(Operator::Inc), 1))),
+            )
+            .then(
+                Stmt::relate()
+                    .from(("user", "tobie").into())
+                    .edge("wrote".into())
+                    .to(("post", "one").into()),
+            )
+            .comment("count posts");
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE EVENT publish ON post WHEN $event = 'CREATE' THEN (UPDATE stats:post SET count += 1), (RELATE user:tobie -> wrote -> post:one) COMMENT 'count posts'"
+        );
+    }
+
+    #[test]
+    fn no_when() {
+        let define =
+            DefineEventStmt::new("log", "user".into()).then(Stmt::create().table("log".into()));
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE EVENT log ON user WHEN true THEN (CREATE log)"
+        );
+    }
+
+    #[test]
+    fn shared_table() {
+        let user = DefineTableStmt::new("user").table();
+        let define = DefineEventStmt::new("log", user).then(Stmt::create().table("log".into()));
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE EVENT log ON user WHEN true THEN (CREATE log)"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn thing() {
+        let _ = DefineEventStmt::new("log", ("user", "tobie").into());
+    }
+}
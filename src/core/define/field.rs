@@ -0,0 +1,203 @@
+use surrealdb::sql::{statements::DefineFieldStatement, Table, Value};
+
+use crate::core::{
+    sql::{Cond, Field, Kind, Permissions, SurrealTable},
+    StmtBridge,
+};
+use crate::impl_stmt_bridge;
+
+/// ## create DEFINE FIELD statement
+/// The DEFINE FIELD statement allows you to instantiate a named field on a table,
+/// enabling you to set the field's data type, set a default value, apply assertions to protect data consistency,
+/// and set permissions specifying what operations can be performed on the field.
+/// ```
+/// DEFINE FIELD @field ON [ TABLE ] @table
+///     [ FLEXIBLE ]
+///     [ TYPE @type ]
+///     [ DEFAULT @expression ]
+///     [ VALUE @expression ]
+///     [ ASSERT @expression ]
+///     [ PERMISSIONS [ NONE | FULL
+///         | FOR select @expression
+///         | FOR create @expression
+///         | FOR update @expression
+///     ] ]
+/// ```
+/// ### example
+/// ```
+/// let define = DefineFieldStmt::new("email", "user".into())
+///     .kind(sql::Kind::String)
+///     .assert(
+///         Cond::new()
+///             .left_value(Value::Param("value".into()))
+///             .op(Operator::NotEqual)
+///             .right(Value::None),
+///     );
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE FIELD email ON user TYPE string ASSERT $value != NONE PERMISSIONS FULL"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineFieldStmt {
+    origin: DefineFieldStatement,
+}
+
+impl DefineFieldStmt {
+    /// ## create DEFINE FIELD statement
+    /// - field : field path such as `name` , `address.city`
+    /// - table : which table the field belongs to, only normal table can be used
+    pub fn new(field: impl Into<Field>, table: SurrealTable) -> Self {
+        DefineFieldStmt {
+            origin: DefineFieldStatement {
+                name: field.into().to_idiom(),
+                what: Table::from(table).0.into(),
+                ..Default::default()
+            },
+        }
+    }
+    /// ## set keyword FLEXIBLE
+    /// allow the field to be schemaless in a SCHEMAFULL table
+    pub fn flexible(mut self) -> Self {
+        self.origin.flex = true;
+        self
+    }
+    /// ## set TYPE
+    pub fn kind(mut self, kind: impl Into<Kind>) -> Self {
+        self.origin.kind.replace(kind.into().to_origin());
+        self
+    }
+    /// ## set VALUE
+    /// the value will be set every time the record is created or updated
+    pub fn value(mut self, value: impl Into<Value>) -> Self {
+        self.origin.value.replace(value.into());
+        self
+    }
+    /// ## set DEFAULT
+    /// the value will be used when the field is not set
+    pub fn default(mut self, value: impl Into<Value>) -> Self {
+        self.origin.default.replace(value.into());
+        self
+    }
+    /// ## set ASSERT
+    /// the value of the field can be referenced by `$value`
+    pub fn assert(mut self, cond: Cond) -> Self {
+        self.origin.assert.replace(cond.to_origin().0);
+        self
+    }
+    /// ## set PERMISSIONS
+    pub fn permissions(mut self, permissions: Permissions) -> Self {
+        self.origin.permissions = permissions.into();
+        self
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+    /// field path as Field, which can be shared with SetField, Cond ...
+    pub fn field(&self) -> Field {
+        self.origin.name.0.clone().into()
+    }
+}
+
+impl ToString for DefineFieldStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineFieldStmt,
+    DefineFieldStatement,
+    Define(DefineStatement::Field)
+);
+
+#[cfg(test)]
+mod test_define_field_stmt {
+    use surrealdb::sql::{self, Duration, Operator, Permission, Thing, Value};
+
+    use crate::core::{
+        define::DefineTableStmt,
+        sql::{Cond, Kind, Permissions, SetField},
+    };
+
+    use super::DefineFieldStmt;
+
+    #[test]
+    fn simple() {
+        let define = DefineFieldStmt::new("email", "user".into())
+            .kind(sql::Kind::String)
+            .assert(
+                Cond::new()
+                    .left_value(Value::Param("value".into()))
+                    .op(Operator::NotEqual)
+                    .right(Value::None),
+            );
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE FIELD email ON user TYPE string ASSERT $value != NONE PERMISSIONS FULL"
+        );
+    }
+
+    #[test]
+    fn complex() {
+        let define = DefineFieldStmt::new("address.city", "user".into())
+            .flexible()
+            .kind(Kind::option(sql::Kind::String))
+            .default("Shanghai")
+            .permissions(
+                Permissions::full().update(
+                    Cond::new()
+                        .left("id")
+                        .op(Operator::Equal)
+                        .right(Value::Param("auth".into())),
+                ),
+            )
+            .comment("city of user");
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE FIELD address.city ON user FLEXIBLE TYPE option<string> DEFAULT 'Shanghai' COMMENT 'city of user' PERMISSIONS FOR select, create, delete FULL, FOR update WHERE id = $auth"
+        );
+    }
+
+    #[test]
+    fn record_array() {
+        let friends = DefineFieldStmt::new("friends", "user".into())
+            .kind(Kind::array(Kind::record(vec!["user"]), None))
+            .permissions(Permissions::full().select(Permission::None));
+        let updated = DefineFieldStmt::new("updated_at", "user".into())
+            .kind(sql::Kind::Duration)
+            .value(Duration::from_secs(60));
+        assert_eq!(
+            friends.to_string().as_str(),
+            "DEFINE FIELD friends ON user TYPE array<record<user>> PERMISSIONS FOR select NONE, FOR create, update, delete FULL"
+        );
+        assert_eq!(
+            updated.to_string().as_str(),
+            "DEFINE FIELD updated_at ON user TYPE duration VALUE 1m PERMISSIONS FULL"
+        );
+        // share the field path with SetField
+        let set = SetField::new(
+            friends.field(),
+            Some(Operator::Inc),
+            Thing::from(("user", "tobie")),
+        );
+        assert_eq!(set.to_string().as_str(), "friends += user:tobie");
+    }
+
+    #[test]
+    fn shared_table() {
+        let user = DefineTableStmt::new("user").table();
+        let define = DefineFieldStmt::new("email", user).kind(sql::Kind::String);
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE FIELD email ON user TYPE string PERMISSIONS FULL"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn thing() {
+        let _ = DefineFieldStmt::new("email", ("user", "tobie").into());
+    }
+}
@@ -0,0 +1,184 @@
+use surrealdb::sql::{statements::DefineFunctionStatement, Permission, Statement, Value};
+
+use crate::core::{
+    push_entry,
+    sql::{Function, Kind},
+    BlockStmt, StmtBridge,
+};
+use crate::impl_stmt_bridge;
+
+/// ## create DEFINE FUNCTION statement
+/// The DEFINE FUNCTION statement allows you to define custom functions that can be reused throughout a database.
+/// Use `DefineFunctionStmt::call` to build the call expression,
+/// so that the call sites always match the definition.
+/// ```
+/// DEFINE FUNCTION fn::@name( [ @argument: @type ... ] ) {
+///     [ @query ... ]
+///     [ RETURN @returned ]
+/// } [ COMMENT @string ] [ PERMISSIONS [ NONE | FULL | WHERE @condition ] ]
+/// ```
+/// ### example
+/// ```
+/// let define = DefineFunctionStmt::new("relation_exists")
+///     .arg("in", Kind::record(vec![]))
+///     .arg("tb", sql::Kind::String)
+///     .push(Stmt::select().table(("person", "tobie").into()).field_all());
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE FUNCTION fn::relation_exists($in: record, $tb: string) { SELECT * FROM person:tobie; } PERMISSIONS FULL"
+/// );
+/// let call = define.call(vec![Thing::from(("person", "tobie")).into(), "likes".into()]);
+/// assert_eq!(
+///     call.to_string().as_str(),
+///     "fn::relation_exists(person:tobie, 'likes')"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineFunctionStmt {
+    origin: DefineFunctionStatement,
+}
+
+impl DefineFunctionStmt {
+    /// ## create DEFINE FUNCTION statement
+    /// - name : name of the function, with or without `fn::`
+    pub fn new(name: &str) -> Self {
+        DefineFunctionStmt {
+            origin: DefineFunctionStatement {
+                name: name.trim_start_matches("fn::").into(),
+                ..Default::default()
+            },
+        }
+    }
+    /// ## add an argument
+    /// `$name: type`
+    pub fn arg(mut self, name: &str, kind: impl Into<Kind>) -> Self {
+        self.origin
+            .args
+            .push((name.into(), kind.into().to_origin()));
+        self
+    }
+    /// ## push a statement into the function body
+    /// any statement which implement BlockStmt
+    pub fn push<S>(mut self, stmt: S) -> Self
+    where
+        S: BlockStmt,
+    {
+        push_entry(&mut self.origin.block, stmt.into());
+        self
+    }
+    /// ## push an expression into the function body
+    /// the last expression of the body is the result of the function
+    pub fn value(mut self, value: impl Into<Value>) -> Self {
+        push_entry(&mut self.origin.block, Statement::Value(value.into()));
+        self
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+    /// ## set PERMISSIONS
+    /// default: `PERMISSIONS FULL`
+    pub fn permissions(mut self, permission: impl Into<Permission>) -> Self {
+        self.origin.permissions = permission.into();
+        self
+    }
+    /// name of the function, without `fn::`
+    pub fn name(&self) -> &str {
+        &self.origin.name
+    }
+    /// ## call the function
+    /// panic if the number of args does not match the definition
+    pub fn call(&self, args: Vec<Value>) -> Function {
+        if args.len() != self.origin.args.len() {
+            panic!(
+                "fn::{} expects {} args, but got {}",
+                self.name(),
+                self.origin.args.len(),
+                args.len()
+            );
+        }
+        Function::custom(self.name(), args)
+    }
+}
+
+impl ToString for DefineFunctionStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineFunctionStmt,
+    DefineFunctionStatement,
+    Define(DefineStatement::Function)
+);
+
+#[cfg(test)]
+mod test_define_function_stmt {
+    use surrealdb::sql::{self, Expression, Operator, Permission, Value};
+
+    use crate::core::{
+        sql::{Cond, Field, Kind},
+        Stmt,
+    };
+
+    use super::DefineFunctionStmt;
+
+    fn greet() -> DefineFunctionStmt {
+        DefineFunctionStmt::new("fn::greet")
+            .arg("name", sql::Kind::String)
+            .value(Value::Expression(Box::new(Expression::Binary {
+                l: "Hello, ".into(),
+                o: Operator::Add,
+                r: Value::Param("name".into()),
+            })))
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(
+            greet().to_string().as_str(),
+            "DEFINE FUNCTION fn::greet($name: string) { 'Hello, ' + $name } PERMISSIONS FULL"
+        );
+    }
+
+    #[test]
+    fn block() {
+        let define = DefineFunctionStmt::new("create_user")
+            .arg("name", Kind::option(sql::Kind::String))
+            .push(Stmt::create().table("user".into()))
+            .push(Stmt::select().table("user".into()).field_all())
+            .comment("create a user")
+            .permissions(Permission::None);
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE FUNCTION fn::create_user($name: option<string>) {\nCREATE user;\nSELECT * FROM user;\n} COMMENT 'create a user' PERMISSIONS NONE"
+        );
+    }
+
+    #[test]
+    fn call() {
+        let define = greet();
+        let select = Stmt::select()
+            .table("person".into())
+            .fields(vec![define
+                .call(vec![Field::new("name").into()])
+                .alias("greeting")])
+            .cond(
+                Cond::new()
+                    .left(define.call(vec![Field::new("name").into()]))
+                    .op(Operator::Equal)
+                    .right("Hello, Tobie".into()),
+            );
+        assert_eq!(
+            select.to_string().as_str(),
+            "SELECT fn::greet(name) AS greeting FROM person WHERE fn::greet(name) = 'Hello, Tobie'"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn call_mismatch() {
+        let _ = greet().call(vec![]);
+    }
+}
@@ -0,0 +1,254 @@
+use serde_json::{json, Value};
+use surrealdb::sql::{statements::DefineIndexStatement, Idiom, Idioms, Index, Table};
+
+use crate::core::{
+    sql::{Distance, Field, SurrealTable, VectorType},
+    StmtBridge,
+};
+use crate::impl_stmt_bridge;
+
+/// ## create DEFINE INDEX statement
+/// Just like in other databases, SurrealDB uses indexes to help optimize query performance.
+/// An index can consist of one or more fields in a table and can enforce a uniqueness constraint.
+/// ```
+/// DEFINE INDEX @name ON [ TABLE ] @table [ FIELDS | COLUMNS ] @fields
+///     [ UNIQUE
+///         | SEARCH ANALYZER @analyzer [ BM25 [(@k1, @b)] ] [ HIGHLIGHTS ]
+///         | MTREE DIMENSION @dimension [ DIST @distance ] [ TYPE @vector_type ] [ CAPACITY @capacity ]
+///     ]
+/// ```
+/// ### example
+/// ```
+/// const IDX_EMAIL: &str = "userEmailIndex";
+/// let define = DefineIndexStmt::new(IDX_EMAIL, "user".into())
+///     .fields(vec![Field::new("email")])
+///     .unique();
+/// let select = Stmt::select()
+///     .table("user".into())
+///     .field_all()
+///     .with_index(vec![IDX_EMAIL]);
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE INDEX userEmailIndex ON user FIELDS email UNIQUE"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineIndexStmt {
+    origin: DefineIndexStatement,
+}
+
+impl DefineIndexStmt {
+    /// ## create DEFINE INDEX statement
+    /// - name : name of the index, which can be used in `SelectStmt::with_index`
+    /// - table : which table the index belongs to, only normal table can be used
+    pub fn new(name: &str, table: SurrealTable) -> Self {
+        DefineIndexStmt {
+            origin: DefineIndexStatement {
+                name: name.into(),
+                what: Table::from(table).0.into(),
+                ..Default::default()
+            },
+        }
+    }
+    /// ## set FIELDS
+    pub fn fields(mut self, fields: Vec<Field>) -> Self {
+        self.origin.cols = Idioms(
+            fields
+                .into_iter()
+                .map(|x| x.to_idiom())
+                .collect::<Vec<Idiom>>(),
+        );
+        self
+    }
+    /// ## set keyword UNIQUE
+    pub fn unique(mut self) -> Self {
+        self.origin.index = Index::Uniq;
+        self
+    }
+    /// ## set SEARCH ANALYZER @analyzer BM25
+    /// full-text search index, use BM25(1.2,0.75) as default scoring
+    pub fn search(mut self, analyzer: &str) -> Self {
+        self.origin.index = to_index(json!({
+            "Search": {
+                "az": analyzer,
+                "hl": false,
+                "sc": { "Bm": { "k1": 1.2, "b": 0.75 } },
+                "doc_ids_order": 100,
+                "doc_lengths_order": 100,
+                "postings_order": 100,
+                "terms_order": 100
+            }
+        }));
+        self
+    }
+    /// ## set BM25(@k1, @b) for SEARCH index
+    pub fn bm25(self, k1: f32, b: f32) -> Self {
+        self.patch("Search", "sc", json!({ "Bm": { "k1": k1, "b": b } }))
+    }
+    /// ## set keyword HIGHLIGHTS for SEARCH index
+    /// enable `search::highlight` function
+    pub fn highlights(self) -> Self {
+        self.patch("Search", "hl", json!(true))
+    }
+    /// ## set MTREE DIMENSION @dimension
+    /// vector index, use DIST EUCLIDEAN TYPE F64 CAPACITY 40 as default
+    pub fn mtree(mut self, dimension: u16) -> Self {
+        self.origin.index = to_index(json!({
+            "MTree": {
+                "dimension": dimension,
+                "distance": Value::from(Distance::default()),
+                "vector_type": Value::from(VectorType::default()),
+                "capacity": 40,
+                "doc_ids_order": 100
+            }
+        }));
+        self
+    }
+    /// ## set DIST for MTREE index
+    pub fn distance(self, distance: Distance) -> Self {
+        self.patch("MTree", "distance", distance.into())
+    }
+    /// ## set TYPE for MTREE index
+    pub fn vector_type(self, vector_type: VectorType) -> Self {
+        self.patch("MTree", "vector_type", vector_type.into())
+    }
+    /// ## set CAPACITY for MTREE index
+    pub fn capacity(self, capacity: u16) -> Self {
+        self.patch("MTree", "capacity", json!(capacity))
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+    /// name of the index
+    pub fn name(&self) -> &str {
+        &self.origin.name
+    }
+    /// replace a param of SEARCH | MTREE index
+    fn patch(mut self, index: &str, key: &str, value: Value) -> Self {
+        let mut origin = serde_json::to_value(&self.origin.index).unwrap();
+        match origin.get_mut(index) {
+            Some(params) => params[key] = value,
+            None => panic!("{} can only be used in {} index", key, index),
+        };
+        self.origin.index = to_index(origin);
+        self
+    }
+}
+
+impl ToString for DefineIndexStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineIndexStmt,
+    DefineIndexStatement,
+    Define(DefineStatement::Index)
+);
+
+/// The params of SEARCH and MTREE index are not exported by surrealdb,
+/// so Index is built from its serialized form
+fn to_index(value: Value) -> Index {
+    match serde_json::from_value(value) {
+        Ok(index) => index,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[cfg(test)]
+mod test_define_index_stmt {
+    use crate::core::{
+        define::DefineTableStmt,
+        select::SelectStmt,
+        sql::{Distance, Field, VectorType},
+    };
+
+    use super::DefineIndexStmt;
+
+    const IDX_EMAIL: &str = "userEmailIndex";
+
+    #[test]
+    fn simple() {
+        let normal =
+            DefineIndexStmt::new("userAgeIndex", "user".into()).fields(vec![Field::new("age")]);
+        let unique = DefineIndexStmt::new(IDX_EMAIL, "user".into())
+            .fields(vec![Field::new("email"), Field::new("account.name")])
+            .unique();
+        assert_eq!(
+            normal.to_string().as_str(),
+            "DEFINE INDEX userAgeIndex ON user FIELDS age"
+        );
+        assert_eq!(
+            unique.to_string().as_str(),
+            "DEFINE INDEX userEmailIndex ON user FIELDS email, account.name UNIQUE"
+        );
+        let select = SelectStmt::new()
+            .table("user".into())
+            .field_all()
+            .with_index(vec![unique.name()]);
+        assert_eq!(
+            select.to_string().as_str(),
+            "SELECT * FROM user WITH INDEX userEmailIndex"
+        );
+    }
+
+    #[test]
+    fn search() {
+        let define = DefineIndexStmt::new("userNameIndex", "user".into())
+            .fields(vec![Field::new("name")])
+            .search("ascii")
+            .bm25(1.5, 0.5)
+            .highlights();
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE INDEX userNameIndex ON user FIELDS name SEARCH ANALYZER ascii BM25(1.5,0.5) DOC_IDS_ORDER 100 DOC_LENGTHS_ORDER 100 POSTINGS_ORDER 100 TERMS_ORDER 100 HIGHLIGHTS"
+        );
+    }
+
+    #[test]
+    fn mtree() {
+        let define = DefineIndexStmt::new("idx_mtree_embedding", "Document".into())
+            .fields(vec![Field::new("items.embedding")])
+            .mtree(4)
+            .distance(Distance::Manhattan)
+            .vector_type(VectorType::I32)
+            .comment("embedding");
+        let minkowski = DefineIndexStmt::new("idx_mtree_embedding", "Document".into())
+            .fields(vec![Field::new("items.embedding")])
+            .mtree(4)
+            .distance(Distance::Minkowski(3))
+            .capacity(50);
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE INDEX idx_mtree_embedding ON Document FIELDS items.embedding MTREE DIMENSION 4 DIST MANHATTAN TYPE I32 CAPACITY 40 DOC_IDS_ORDER 100 COMMENT 'embedding'"
+        );
+        assert_eq!(
+            minkowski.to_string().as_str(),
+            "DEFINE INDEX idx_mtree_embedding ON Document FIELDS items.embedding MTREE DIMENSION 4 DIST MINKOWSKI 3 TYPE F64 CAPACITY 50 DOC_IDS_ORDER 100"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn highlights_without_search() {
+        let _ = DefineIndexStmt::new("userNameIndex", "user".into()).highlights();
+    }
+
+    #[test]
+    fn shared_table() {
+        let user = DefineTableStmt::new("user").table();
+        let define = DefineIndexStmt::new("userAgeIndex", user).fields(vec![Field::new("age")]);
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE INDEX userAgeIndex ON user FIELDS age"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn thing() {
+        let _ = DefineIndexStmt::new("userAgeIndex", ("user", "tobie").into());
+    }
+}
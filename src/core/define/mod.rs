@@ -0,0 +1,37 @@
+/// DEFINE ANALYZER statement
+mod analyzer;
+/// DEFINE DATABASE statement
+mod database;
+/// DEFINE EVENT statement
+mod event;
+/// DEFINE FIELD statement
+mod field;
+/// DEFINE FUNCTION statement
+mod function;
+/// DEFINE INDEX statement
+mod index;
+/// DEFINE NAMESPACE statement
+mod namespace;
+/// DEFINE PARAM statement
+mod param;
+/// DEFINE SCOPE statement
+mod scope;
+/// DEFINE TABLE statement
+mod table;
+/// DEFINE TOKEN statement
+mod token;
+/// DEFINE USER statement
+mod user;
+
+pub use analyzer::DefineAnalyzerStmt;
+pub use database::DefineDatabaseStmt;
+pub use event::DefineEventStmt;
+pub use field::DefineFieldStmt;
+pub use function::DefineFunctionStmt;
+pub use index::DefineIndexStmt;
+pub use namespace::DefineNamespaceStmt;
+pub use param::DefineParamStmt;
+pub use scope::DefineScopeStmt;
+pub use table::DefineTableStmt;
+pub use token::DefineTokenStmt;
+pub use user::DefineUserStmt;
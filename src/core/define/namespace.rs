@@ -0,0 +1,70 @@
+use surrealdb::sql::statements::DefineNamespaceStatement;
+
+use crate::core::StmtBridge;
+use crate::impl_stmt_bridge;
+
+/// ## create DEFINE NAMESPACE statement
+/// SurrealDB has a multi-tenancy model which allows you to scope databases to a namespace.
+/// There is no limit to the number of databases that can be in a namespace,
+/// nor is there a limit to the number of namespaces allowed.
+/// ```
+/// DEFINE NAMESPACE @name
+/// ```
+/// ### example
+/// ```
+/// let define = DefineNamespaceStmt::new("abcum").comment("tenant");
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE NAMESPACE abcum COMMENT 'tenant'"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineNamespaceStmt {
+    origin: DefineNamespaceStatement,
+}
+
+impl DefineNamespaceStmt {
+    pub fn new(name: &str) -> Self {
+        DefineNamespaceStmt {
+            origin: DefineNamespaceStatement {
+                name: name.into(),
+                ..Default::default()
+            },
+        }
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+    /// name of the namespace
+    pub fn name(&self) -> &str {
+        &self.origin.name
+    }
+}
+
+impl ToString for DefineNamespaceStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineNamespaceStmt,
+    DefineNamespaceStatement,
+    Define(DefineStatement::Namespace)
+);
+
+#[cfg(test)]
+mod test_define_namespace_stmt {
+    use super::DefineNamespaceStmt;
+
+    #[test]
+    fn simple() {
+        let define = DefineNamespaceStmt::new("abcum");
+        assert_eq!(define.to_string().as_str(), "DEFINE NAMESPACE abcum");
+        assert_eq!(
+            define.comment("tenant").to_string().as_str(),
+            "DEFINE NAMESPACE abcum COMMENT 'tenant'"
+        );
+    }
+}
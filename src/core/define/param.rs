@@ -0,0 +1,105 @@
+use surrealdb::sql::{statements::DefineParamStatement, Permission, Value};
+
+use crate::core::StmtBridge;
+use crate::impl_stmt_bridge;
+
+/// ## create DEFINE PARAM statement
+/// The DEFINE PARAM statement allows you to define global (database-wide) parameters
+/// that are available to every client.
+/// ```
+/// DEFINE PARAM $@name VALUE @value
+///     [ PERMISSIONS [ NONE | FULL | WHERE @condition ] ]
+/// ```
+/// ### example
+/// ```
+/// let define = DefineParamStmt::new("endpointBase", "https://dummyjson.com");
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE PARAM $endpointBase VALUE 'https://dummyjson.com' PERMISSIONS FULL"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineParamStmt {
+    origin: DefineParamStatement,
+}
+
+impl DefineParamStmt {
+    /// ## create DEFINE PARAM statement
+    /// - name : name of the param, with or without `$`
+    /// - value : value of the param
+    pub fn new(name: &str, value: impl Into<Value>) -> Self {
+        DefineParamStmt {
+            origin: DefineParamStatement {
+                name: name.trim_start_matches('$').into(),
+                value: value.into(),
+                ..Default::default()
+            },
+        }
+    }
+    /// ## set PERMISSIONS
+    /// default: `PERMISSIONS FULL`
+    pub fn permissions(mut self, permission: impl Into<Permission>) -> Self {
+        self.origin.permissions = permission.into();
+        self
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+    /// name of the param, without `$`
+    pub fn name(&self) -> &str {
+        &self.origin.name
+    }
+}
+
+impl ToString for DefineParamStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineParamStmt,
+    DefineParamStatement,
+    Define(DefineStatement::Param)
+);
+
+#[cfg(test)]
+mod test_define_param_stmt {
+    use surrealdb::sql::{Operator, Permission, Value};
+
+    use crate::core::sql::Cond;
+
+    use super::DefineParamStmt;
+
+    #[test]
+    fn simple() {
+        let define = DefineParamStmt::new("$endpointBase", "https://dummyjson.com");
+        assert_eq!(define.name(), "endpointBase");
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE PARAM $endpointBase VALUE 'https://dummyjson.com' PERMISSIONS FULL"
+        );
+    }
+
+    #[test]
+    fn permissions() {
+        let none = DefineParamStmt::new("limit", 100).permissions(Permission::None);
+        let cond = DefineParamStmt::new("limit", 100)
+            .permissions(
+                Cond::new()
+                    .left_value(Value::Param("auth".into()))
+                    .op(Operator::NotEqual)
+                    .right(Value::None),
+            )
+            .comment("page size");
+        assert_eq!(
+            none.to_string().as_str(),
+            "DEFINE PARAM $limit VALUE 100 PERMISSIONS NONE"
+        );
+        assert_eq!(
+            cond.to_string().as_str(),
+            "DEFINE PARAM $limit VALUE 100 COMMENT 'page size' PERMISSIONS WHERE $auth != NONE"
+        );
+    }
+}
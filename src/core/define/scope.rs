@@ -0,0 +1,321 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use surrealdb::sql::{self, statements::DefineScopeStatement};
+
+use crate::config::auth::Scope;
+use crate::core::{StmtBridge, SubqueryStmt};
+use crate::impl_stmt_bridge;
+
+/// params provided by SurrealDB, which are not passed in by `Scope<P>`
+const RESERVED_PARAMS: [&str; 11] = [
+    "auth", "session", "scope", "token", "this", "parent", "value", "input", "before", "after",
+    "event",
+];
+
+/// ## create DEFINE SCOPE statement
+/// Setting scope access allows SurrealDB to operate as a web database.
+/// With scopes you can set authentication and access rules which enable fine-grained access to tables and fields.
+/// The params used in SIGNUP and SIGNIN should be the fields of `P` in `config::auth::Scope<P>`,
+/// use `DefineScopeStmt::is_match` to check it
+/// ```
+/// DEFINE SCOPE @name SESSION @duration SIGNUP @expression SIGNIN @expression
+/// ```
+/// ### example
+/// ```
+/// let define = DefineScopeStmt::new("account")
+///     .session(Duration::from_hours(24))
+///     .signup(
+///         Stmt::create().table("user".into()).data(
+///             CreateData::set()
+///                 .push(SetField::new("email", None, Value::Param("email".into())))
+///                 .push(SetField::new("pass", None, Value::Param("pass".into()))),
+///         ),
+///     )
+///     .signin(
+///         Stmt::select().table("user".into()).field_all().cond(
+///             Cond::new()
+///                 .left("email")
+///                 .op(Operator::Equal)
+///                 .right(Value::Param("email".into())),
+///         ),
+///     );
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE SCOPE account SESSION 1d SIGNUP (CREATE user SET email = $email, pass = $pass) SIGNIN (SELECT * FROM user WHERE email = $email)"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineScopeStmt {
+    origin: DefineScopeStatement,
+}
+
+impl DefineScopeStmt {
+    /// ## create DEFINE SCOPE statement
+    /// - name : name of the scope, which is the `sc` of `config::auth::Scope<P>`
+    pub fn new(name: &str) -> Self {
+        DefineScopeStmt {
+            origin: DefineScopeStatement {
+                name: name.into(),
+                code: DefineScopeStatement::random_code(),
+                ..Default::default()
+            },
+        }
+    }
+    /// ## set SESSION
+    /// how long the session of the scope user lasts
+    pub fn session(mut self, duration: sql::Duration) -> Self {
+        self.origin.session.replace(duration);
+        self
+    }
+    /// ## set SIGNUP
+    /// such as: `CreateStmt`
+    pub fn signup<S>(mut self, stmt: S) -> Self
+    where
+        S: SubqueryStmt,
+    {
+        self.origin.signup.replace(stmt.into());
+        self
+    }
+    /// ## set SIGNIN
+    /// such as: `SelectStmt`
+    pub fn signin<S>(mut self, stmt: S) -> Self
+    where
+        S: SubqueryStmt,
+    {
+        self.origin.signin.replace(stmt.into());
+        self
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+    /// name of the scope
+    pub fn name(&self) -> &str {
+        &self.origin.name
+    }
+    /// ## params used in SIGNUP and SIGNIN
+    /// without the params provided by SurrealDB such as `$auth` , `$session`
+    pub fn params(&self) -> BTreeSet<String> {
+        let mut params = BTreeSet::new();
+        [&self.origin.signup, &self.origin.signin]
+            .into_iter()
+            .flatten()
+            .filter_map(|value| serde_json::to_value(value).ok())
+            .for_each(|value| find_params(&value, &mut params));
+        params.retain(|param| !RESERVED_PARAMS.contains(&param.as_str()));
+        params
+    }
+    /// ## check the scope credential
+    /// true if `sc` is the name of the scope
+    /// and the fields of `P` are just the params used in SIGNUP and SIGNIN
+    /// ### example
+    /// ```
+    /// let define = DefineScopeStmt::new("account").signin(
+    ///     Stmt::select().table("user".into()).field_all().cond(
+    ///         Cond::new()
+    ///             .left("email")
+    ///             .op(Operator::Equal)
+    ///             .right(Value::Param("email".into()))
+    ///             .and(
+    ///                 Cond::new()
+    ///                     .left("pass")
+    ///                     .op(Operator::Equal)
+    ///                     .right(Value::Param("pass".into())),
+    ///             ),
+    ///     ),
+    /// );
+    /// #[derive(Debug, Clone, Serialize, Deserialize)]
+    /// struct Params {
+    ///     email: String,
+    ///     pass: String,
+    /// }
+    /// let scope: AuthCredentials<Params> = json!({
+    ///     "ns": "test",
+    ///     "db": "test",
+    ///     "sc": "account",
+    ///     "email": "tobie@surrealdb.com",
+    ///     "pass": "123456",
+    /// })
+    /// .into();
+    /// if let AuthCredentials::SC(Some(scope)) = scope {
+    ///     assert!(define.is_match(&scope));
+    /// }
+    /// ```
+    pub fn is_match<P>(&self, scope: &Scope<P>) -> bool
+    where
+        P: Serialize,
+    {
+        let fields = match serde_json::to_value(&scope.params) {
+            Ok(serde_json::Value::Object(params)) => params.keys().cloned().collect(),
+            _ => BTreeSet::new(),
+        };
+        scope.sc == self.name() && fields == self.params()
+    }
+}
+
+impl ToString for DefineScopeStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineScopeStmt,
+    DefineScopeStatement,
+    Define(DefineStatement::Scope)
+);
+
+/// find `$param` in the serialized statement, where `Value::Param` is `{"Param": name}`
+fn find_params(value: &serde_json::Value, params: &mut BTreeSet<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                match (key.as_str(), value) {
+                    ("Param", serde_json::Value::String(name)) => {
+                        params.insert(name.clone());
+                    }
+                    // keys of an object value are not variants of `Value`
+                    ("Object", serde_json::Value::Object(object)) => {
+                        object.values().for_each(|value| find_params(value, params))
+                    }
+                    _ => find_params(value, params),
+                }
+            }
+        }
+        serde_json::Value::Array(values) => {
+            values.iter().for_each(|value| find_params(value, params))
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod test_define_scope_stmt {
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+    use surrealdb::sql::{Duration, Operator, Value};
+
+    use crate::{
+        config::auth::{AuthCredentials, Scope},
+        core::{
+            sql::{Cond, CreateData, Function, Param, SetField},
+            Stmt,
+        },
+    };
+
+    use super::DefineScopeStmt;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct Params {
+        email: String,
+        pass: String,
+    }
+
+    fn account() -> DefineScopeStmt {
+        DefineScopeStmt::new("account")
+            .session(Duration::from_hours(24))
+            .signup(
+                Stmt::create().table("user".into()).data(
+                    CreateData::set()
+                        .push(SetField::new("email", None, Value::Param("email".into())))
+                        .push(SetField::new(
+                            "pass",
+                            None,
+                            Function::normal(
+                                "crypto::argon2::generate",
+                                vec![Value::Param("pass".into())],
+                            ),
+                        )),
+                ),
+            )
+            .signin(
+                Stmt::select().table("user".into()).field_all().cond(
+                    Cond::new()
+                        .left("email")
+                        .op(Operator::Equal)
+                        .right(Value::Param("email".into())),
+                ),
+            )
+    }
+
+    fn scope(sc: &str) -> Scope<Params> {
+        let scope: AuthCredentials<Params> = json!({
+            "ns": "test",
+            "db": "test",
+            "sc": sc,
+            "email": "tobie@surrealdb.com",
+            "pass": "123456",
+        })
+        .into();
+        match scope {
+            AuthCredentials::SC(Some(scope)) => scope,
+            _ => panic!("Credentials is not Scope"),
+        }
+    }
+
+    #[test]
+    fn simple() {
+        let define = account().comment("user account");
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE SCOPE account SESSION 1d SIGNUP (CREATE user SET email = $email, pass = crypto::argon2::generate($pass)) SIGNIN (SELECT * FROM user WHERE email = $email) COMMENT 'user account'"
+        );
+    }
+
+    #[test]
+    fn params() {
+        let define = account();
+        assert_eq!(
+            define.params().into_iter().collect::<Vec<String>>(),
+            vec!["email".to_string(), "pass".to_string()]
+        );
+        assert!(define.is_match(&scope("account")));
+        assert!(!define.is_match(&scope("admin")));
+    }
+
+    #[test]
+    fn params_mismatch() {
+        let define = DefineScopeStmt::new("account").signin(
+            Stmt::select().table("user".into()).field_all().cond(
+                Cond::new()
+                    .left("name")
+                    .op(Operator::Equal)
+                    .right(Value::Param("name".into())),
+            ),
+        );
+        assert!(!define.is_match(&scope("account")));
+    }
+
+    #[test]
+    fn params_nested() {
+        let meta = Value::Object(BTreeMap::from([("Param".to_string(), Value::None)]).into());
+        let define = DefineScopeStmt::new("account").signin(
+            Stmt::select().table("user".into()).field_all().cond(
+                Cond::new()
+                    .left("email")
+                    .op(Operator::Equal)
+                    .right(Param::new("credentials").field("email").into())
+                    .and(
+                        Cond::new()
+                            .left("note")
+                            .op(Operator::Equal)
+                            .right("$pass".into()),
+                    )
+                    .and(Cond::new().left("meta").op(Operator::Equal).right(meta))
+                    .and(
+                        Cond::new()
+                            .left("id")
+                            .op(Operator::Equal)
+                            .right(Param::new("auth").field("id").into()),
+                    ),
+            ),
+        );
+        assert_eq!(
+            define.params().into_iter().collect::<Vec<String>>(),
+            vec!["credentials".to_string()]
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use surrealdb::sql::{
+    statements::DefineTableStatement, ChangeFeed, Duration, Table, Tables, Value, View,
+};
+
+use crate::core::{
+    select::SelectStmt,
+    sql::{Permissions, SurrealTable},
+    StmtBridge,
+};
+use crate::impl_stmt_bridge;
+
+/// ## create DEFINE TABLE statement
+/// The DEFINE TABLE statement allows you to declare your table by name,
+/// enabling you to apply strict controls to a table's schema by making it SCHEMAFULL,
+/// create a foreign table view, and set permissions specifying what operations can be performed on the field.
+/// ```
+/// DEFINE TABLE @name
+///     [ DROP ]
+///     [ SCHEMAFULL | SCHEMALESS ]
+///     [ AS SELECT @projections
+///         FROM @tables
+///         [ WHERE @condition ]
+///         [ GROUP [ BY ] @groups ]
+///     ]
+///     [ CHANGEFEED @duration ]
+///     [ PERMISSIONS [ NONE | FULL
+///         | FOR select @expression
+///         | FOR create @expression
+///         | FOR update @expression
+///         | FOR delete @expression
+///     ] ]
+/// ```
+/// ### example
+/// ```
+/// let define = DefineTableStmt::new("post")
+///     .schemafull()
+///     .changefeed(Duration::from_days(3))
+///     .permissions(
+///         Permissions::full()
+///             .create(Cond::new().left("user").op(Operator::Equal).right(Value::Param("auth".into())))
+///             .delete(Permission::None),
+///     );
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE TABLE post SCHEMAFULL CHANGEFEED 3d PERMISSIONS FOR select, update FULL, FOR create WHERE user = $auth, FOR delete NONE"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineTableStmt {
+    origin: DefineTableStatement,
+}
+
+impl DefineTableStmt {
+    /// ## create DEFINE TABLE statement
+    /// default SCHEMALESS and PERMISSIONS NONE
+    pub fn new(name: &str) -> Self {
+        DefineTableStmt {
+            origin: DefineTableStatement {
+                name: name.into(),
+                permissions: Permissions::none().into(),
+                ..Default::default()
+            },
+        }
+    }
+    /// ## set keyword DROP
+    /// the table will not store any records, which is useful for tables only used by events
+    pub fn drop(mut self) -> Self {
+        self.origin.drop = true;
+        self
+    }
+    /// ## set keyword SCHEMAFULL
+    /// only the fields which have been defined can be stored
+    pub fn schemafull(mut self) -> Self {
+        self.origin.full = true;
+        self
+    }
+    /// ## set keyword SCHEMALESS
+    /// default
+    pub fn schemaless(mut self) -> Self {
+        self.origin.full = false;
+        self
+    }
+    /// ## set AS SELECT (foreign table view)
+    /// use an existing SelectStmt, only fields, tables, WHERE and GROUP BY will be used
+    /// ### example
+    /// ```
+    /// let define = DefineTableStmt::new("adult_user").view(
+    ///     SelectStmt::new()
+    ///         .fields(vec![Field::new("name"), Field::new("age")])
+    ///         .table("user".into())
+    ///         .cond(Cond::new().left("age").op(Operator::MoreThanOrEqual).right(18.into())),
+    /// );
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE TABLE adult_user SCHEMALESS AS SELECT name, age FROM user WHERE age >= 18 PERMISSIONS NONE"
+    /// );
+    /// ```
+    pub fn view(mut self, select: SelectStmt) -> Self {
+        let select = select.to_origin();
+        let what = select
+            .what
+            .0
+            .into_iter()
+            .map(|x| match x {
+                Value::Table(table) => table,
+                _ => panic!(
+                    "{} cannot be used as a table in DEFINE TABLE ... AS SELECT",
+                    x
+                ),
+            })
+            .collect::<Vec<Table>>();
+        self.origin.view.replace(View {
+            expr: select.expr,
+            what: Tables(what),
+            cond: select.cond,
+            group: select.group,
+        });
+        self
+    }
+    /// ## set CHANGEFEED
+    /// how long the changes of the table will be kept
+    pub fn changefeed(mut self, expiry: Duration) -> Self {
+        self.origin
+            .changefeed
+            .replace(ChangeFeed { expiry: expiry.0 });
+        self
+    }
+    /// ## set PERMISSIONS
+    pub fn permissions(mut self, permissions: Permissions) -> Self {
+        self.origin.permissions = permissions.into();
+        self
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+    /// table name as SurrealTable, which can be shared with other statements
+    pub fn table(&self) -> SurrealTable {
+        SurrealTable::table(&self.origin.name)
+    }
+}
+
+impl ToString for DefineTableStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineTableStmt,
+    DefineTableStatement,
+    Define(DefineStatement::Table)
+);
+
+#[cfg(test)]
+mod test_define_table_stmt {
+    use surrealdb::sql::{Duration, Operator, Permission, Value};
+
+    use crate::core::{
+        select::SelectStmt,
+        sql::{Cond, Field, Permissions},
+    };
+
+    use super::DefineTableStmt;
+
+    #[test]
+    fn simple() {
+        let schemaless = DefineTableStmt::new("person");
+        let schemafull = DefineTableStmt::new("person").schemafull().drop();
+        assert_eq!(
+            schemaless.to_string().as_str(),
+            "DEFINE TABLE person SCHEMALESS PERMISSIONS NONE"
+        );
+        assert_eq!(
+            schemafull.to_string().as_str(),
+            "DEFINE TABLE person DROP SCHEMAFULL PERMISSIONS NONE"
+        );
+        assert_eq!(schemafull.table().to_string().as_str(), "person");
+    }
+
+    #[test]
+    fn permissions() {
+        let define = DefineTableStmt::new("post")
+            .schemafull()
+            .changefeed(Duration::from_days(3))
+            .permissions(
+                Permissions::full()
+                    .create(
+                        Cond::new()
+                            .left("user")
+                            .op(Operator::Equal)
+                            .right(Value::Param("auth".into())),
+                    )
+                    .delete(Permission::None),
+            );
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE TABLE post SCHEMAFULL CHANGEFEED 3d PERMISSIONS FOR select, update FULL, FOR create WHERE user = $auth, FOR delete NONE"
+        );
+    }
+
+    #[test]
+    fn view() {
+        let define = DefineTableStmt::new("adult_user")
+            .view(
+                SelectStmt::new()
+                    .fields(vec![Field::new("name"), Field::new("age")])
+                    .table("user".into())
+                    .cond(
+                        Cond::new()
+                            .left("age")
+                            .op(Operator::MoreThanOrEqual)
+                            .right(18.into()),
+                    )
+                    .group_by(vec![Field::new("age")]),
+            )
+            .comment("users over 18");
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE TABLE adult_user SCHEMALESS COMMENT 'users over 18' AS SELECT name, age FROM user WHERE age >= 18 GROUP BY age PERMISSIONS NONE"
+        );
+    }
+}
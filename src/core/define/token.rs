@@ -0,0 +1,122 @@
+use surrealdb::sql::{statements::DefineTokenStatement, Algorithm, Base};
+
+use crate::core::StmtBridge;
+use crate::impl_stmt_bridge;
+
+/// ## create DEFINE TOKEN statement
+/// SurrealDB can work with third-party OAuth providers.
+/// Let's say that your provider issues your service a JWT once it's authenticated.
+/// By using the DEFINE TOKEN statement, you can set the public key needed to verify a JWT's authenticity.
+/// ```
+/// DEFINE TOKEN @name ON [ NAMESPACE | DATABASE | SCOPE @scope ] TYPE @type VALUE @value
+/// ```
+/// ### example
+/// ```
+/// let define = DefineTokenStmt::new("token_name")
+///     .on_scope("account")
+///     .kind(Algorithm::Hs512)
+///     .value("sNSYneezcr8kqphfOC6NwwraUHJCVAt0XjsRSNmssBaBRh3WyMa9TRfq8ST7fsU2H2kGiOpU4GbAF1bCiXmM1b3JGgleBzz7rsrz6VvYEM4q3CLkcO8CMBIlhwhzWmy8");
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE TOKEN token_name ON SCOPE account TYPE HS512 VALUE 'sNSYneezcr8kqphfOC6NwwraUHJCVAt0XjsRSNmssBaBRh3WyMa9TRfq8ST7fsU2H2kGiOpU4GbAF1bCiXmM1b3JGgleBzz7rsrz6VvYEM4q3CLkcO8CMBIlhwhzWmy8'"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineTokenStmt {
+    origin: DefineTokenStatement,
+}
+
+impl DefineTokenStmt {
+    /// ## create DEFINE TOKEN statement
+    /// default ON NAMESPACE TYPE HS512
+    pub fn new(name: &str) -> Self {
+        DefineTokenStmt {
+            origin: DefineTokenStatement {
+                name: name.into(),
+                base: Base::Ns,
+                ..Default::default()
+            },
+        }
+    }
+    /// ## ON NAMESPACE
+    /// default
+    pub fn on_ns(mut self) -> Self {
+        self.origin.base = Base::Ns;
+        self
+    }
+    /// ## ON DATABASE
+    pub fn on_db(mut self) -> Self {
+        self.origin.base = Base::Db;
+        self
+    }
+    /// ## ON SCOPE @scope
+    pub fn on_scope(mut self, scope: &str) -> Self {
+        self.origin.base = Base::Sc(scope.into());
+        self
+    }
+    /// ## set TYPE
+    /// the algorithm used to verify the token, default HS512
+    pub fn kind(mut self, algorithm: Algorithm) -> Self {
+        self.origin.kind = algorithm;
+        self
+    }
+    /// ## set VALUE
+    /// the secret or public key used to verify the token
+    pub fn value(mut self, value: &str) -> Self {
+        self.origin.code = value.to_string();
+        self
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+}
+
+impl ToString for DefineTokenStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineTokenStmt,
+    DefineTokenStatement,
+    Define(DefineStatement::Token)
+);
+
+#[cfg(test)]
+mod test_define_token_stmt {
+    use surrealdb::sql::Algorithm;
+
+    use super::DefineTokenStmt;
+
+    #[test]
+    fn simple() {
+        let define = DefineTokenStmt::new("token_name").value("secret");
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE TOKEN token_name ON NAMESPACE TYPE HS512 VALUE 'secret'"
+        );
+    }
+
+    #[test]
+    fn on_scope() {
+        let db = DefineTokenStmt::new("token_name")
+            .on_db()
+            .kind(Algorithm::Rs256)
+            .value("-----BEGIN PUBLIC KEY-----")
+            .comment("oauth");
+        let sc = DefineTokenStmt::new("token_name")
+            .on_scope("account")
+            .kind(Algorithm::EdDSA)
+            .value("secret");
+        assert_eq!(
+            db.to_string().as_str(),
+            "DEFINE TOKEN token_name ON DATABASE TYPE RS256 VALUE '-----BEGIN PUBLIC KEY-----' COMMENT 'oauth'"
+        );
+        assert_eq!(
+            sc.to_string().as_str(),
+            "DEFINE TOKEN token_name ON SCOPE account TYPE EDDSA VALUE 'secret'"
+        );
+    }
+}
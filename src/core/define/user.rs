@@ -0,0 +1,190 @@
+use surrealdb::sql::{statements::DefineUserStatement, Base, Ident};
+
+use crate::config::auth::{Database, Namespace, Root};
+use crate::core::{sql::Role, StmtBridge};
+use crate::impl_stmt_bridge;
+
+/// ## create DEFINE USER statement
+/// Use the DEFINE USER statement to create system users on SurrealDB.
+/// The password is hashed by Argon2, so the statement is always rendered with PASSHASH
+/// ```
+/// DEFINE USER @name ON [ ROOT | NAMESPACE | DATABASE ]
+///     [ PASSWORD @pass | PASSHASH @hash ]
+///     ROLES @roles
+/// ```
+/// credentials in `config::auth` can be converted into DEFINE USER directly:
+/// - `Root` -> `DEFINE USER @user ON ROOT`
+/// - `Namespace` -> `DEFINE USER @user ON NAMESPACE` (should be used after `USE NS @ns`)
+/// - `Database` -> `DEFINE USER @user ON DATABASE` (should be used after `USE NS @ns DB @db`)
+/// ### example
+/// ```
+/// let define = DefineUserStmt::new("username")
+///     .on_db()
+///     .passhash("$argon2id$v=19$m=19456,t=2,p=1$...")
+///     .roles(vec![Role::Owner, Role::Editor]);
+/// assert_eq!(
+///     define.to_string().as_str(),
+///     "DEFINE USER username ON DATABASE PASSHASH '$argon2id$v=19$m=19456,t=2,p=1$...' ROLES OWNER, EDITOR"
+/// );
+/// let root = Root::new("root", "root");
+/// let define = DefineUserStmt::from(&root);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineUserStmt {
+    origin: DefineUserStatement,
+}
+
+impl DefineUserStmt {
+    /// ## create DEFINE USER statement
+    /// default ON ROOT ROLES VIEWER
+    pub fn new(name: &str) -> Self {
+        DefineUserStmt {
+            origin: DefineUserStatement {
+                name: name.into(),
+                base: Base::Root,
+                roles: vec![Role::default().into()],
+                ..Default::default()
+            },
+        }
+    }
+    /// ## ON ROOT
+    /// default
+    pub fn on_root(mut self) -> Self {
+        self.origin.base = Base::Root;
+        self
+    }
+    /// ## ON NAMESPACE
+    pub fn on_ns(mut self) -> Self {
+        self.origin.base = Base::Ns;
+        self
+    }
+    /// ## ON DATABASE
+    pub fn on_db(mut self) -> Self {
+        self.origin.base = Base::Db;
+        self
+    }
+    /// ## set PASSWORD
+    /// the password will be hashed by Argon2
+    pub fn password(mut self, pass: &str) -> Self {
+        let DefineUserStatement { hash, code, .. } =
+            DefineUserStatement::from((self.origin.base.clone(), self.name(), pass));
+        self.origin.hash = hash;
+        self.origin.code = code;
+        self
+    }
+    /// ## set PASSHASH
+    /// the password which has been hashed by Argon2
+    pub fn passhash(mut self, hash: &str) -> Self {
+        self.origin.hash = hash.to_string();
+        self
+    }
+    /// ## set ROLES
+    pub fn roles(mut self, roles: Vec<Role>) -> Self {
+        self.origin.roles = roles.into_iter().map(Ident::from).collect::<Vec<Ident>>();
+        self
+    }
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.origin.comment.replace(comment.into());
+        self
+    }
+    /// name of the user
+    pub fn name(&self) -> &str {
+        &self.origin.name
+    }
+}
+
+impl ToString for DefineUserStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(
+    DefineUserStmt,
+    DefineUserStatement,
+    Define(DefineStatement::User)
+);
+
+/// `DEFINE USER @user ON ROOT PASSWORD @pass ROLES OWNER`
+impl From<&Root> for DefineUserStmt {
+    fn from(value: &Root) -> Self {
+        DefineUserStmt::new(value.user())
+            .on_root()
+            .password(value.pass())
+            .roles(vec![Role::Owner])
+    }
+}
+
+/// `DEFINE USER @user ON NAMESPACE PASSWORD @pass ROLES OWNER`
+impl From<&Namespace> for DefineUserStmt {
+    fn from(value: &Namespace) -> Self {
+        DefineUserStmt::new(value.user())
+            .on_ns()
+            .password(value.pass())
+            .roles(vec![Role::Owner])
+    }
+}
+
+/// `DEFINE USER @user ON DATABASE PASSWORD @pass ROLES OWNER`
+impl From<&Database> for DefineUserStmt {
+    fn from(value: &Database) -> Self {
+        DefineUserStmt::new(value.user())
+            .on_db()
+            .password(value.pass())
+            .roles(vec![Role::Owner])
+    }
+}
+
+#[cfg(test)]
+mod test_define_user_stmt {
+    use crate::{
+        config::auth::{Database, Namespace, Root},
+        core::sql::Role,
+    };
+
+    use super::DefineUserStmt;
+
+    const HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$...";
+
+    #[test]
+    fn simple() {
+        let define = DefineUserStmt::new("username").passhash(HASH);
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE USER username ON ROOT PASSHASH '$argon2id$v=19$m=19456,t=2,p=1$...' ROLES VIEWER"
+        );
+    }
+
+    #[test]
+    fn roles() {
+        let define = DefineUserStmt::new("username")
+            .on_db()
+            .passhash(HASH)
+            .roles(vec![Role::Owner, Role::Editor])
+            .comment("admin");
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE USER username ON DATABASE PASSHASH '$argon2id$v=19$m=19456,t=2,p=1$...' ROLES OWNER, EDITOR COMMENT 'admin'"
+        );
+    }
+
+    #[test]
+    fn password() {
+        let define = DefineUserStmt::new("username").on_ns().password("123456");
+        let define = define.to_string();
+        assert!(define.starts_with("DEFINE USER username ON NAMESPACE PASSHASH '$argon2id$"));
+        assert!(define.ends_with("' ROLES VIEWER"));
+        assert!(!define.contains("123456"));
+    }
+
+    #[test]
+    fn from_credentials() {
+        let root = DefineUserStmt::from(&Root::new("root", "root")).to_string();
+        let ns = DefineUserStmt::from(&Namespace::new("admin", "admin", "test")).to_string();
+        let db = DefineUserStmt::from(&Database::new("test", "test", "user", "user")).to_string();
+        assert!(root.starts_with("DEFINE USER root ON ROOT PASSHASH '$argon2id$"));
+        assert!(ns.starts_with("DEFINE USER admin ON NAMESPACE PASSHASH '$argon2id$"));
+        assert!(db.starts_with("DEFINE USER user ON DATABASE PASSHASH '$argon2id$"));
+        assert!(root.ends_with("' ROLES OWNER"));
+    }
+}
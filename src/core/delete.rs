@@ -1,37 +1,3 @@
-//! 待解决问题1 : Edges -> Cond 转换
-//! ````
-//! fn delete_timeout() {
-//!     let inner_where = format!(
-//!         "({} {})",
-//!         "knows",
-//!         Cond::new()
-//!             .left_easy("influencer")
-//!             .op(Operator::Equal)
-//!             .right(false.into())
-//!             .to_string()
-//!     );
-//!     // let where_edges:Edges = ("a",Dir::Out,"b").into();
-//!
-//!     // ((("",Dir::Out,"knows"),Dir::Out,"person"),Dir::Out,&inner_where)
-//!     let where_edges: Edges = Edges::new(
-//!         Edges::new(
-//!             Edges::new("".into(), Dir::Out, "knows".into()).into(),
-//!             Dir::Out,
-//!             "person".into(),
-//!         )
-//!         .into(),
-//!         Dir::Out,
-//!         inner_where.as_str().into(),
-//!     );
-//!
-//!     let delete = DeleteStmt::new()
-//!         .table("person".into())
-//!         .cond(where_edges.into())
-//!         .timeout(sql::Duration::from_secs(5));
-//!     dbg!(delete.to_string());
-//!     // assert_eq!(delete.to_string().as_str() , "DELETE person WHERE ->knows->person->(knows WHERE influencer = false) TIMEOUT 5s;");
-//! }
-//! ````
 use surrealdb::sql::{statements::DeleteStatement, Duration, Output, Timeout};
 
 use crate::impl_stmt_bridge;
@@ -189,7 +155,7 @@ impl ToString for DeleteStmt {
     }
 }
 
-impl_stmt_bridge!(DeleteStmt, DeleteStatement);
+impl_stmt_bridge!(DeleteStmt, DeleteStatement, Delete);
 
 #[cfg(test)]
 mod test_delete {
@@ -197,10 +163,38 @@ mod test_delete {
         self, statements::DeleteStatement, Expression, Id, Operator, Strand, Table, Value, Values,
     };
 
-    use crate::core::sql::{Cond, Field};
+    use crate::core::sql::{Cond, Edges, Field};
 
     use super::DeleteStmt;
 
+    #[test]
+    fn delete_edges() {
+        let where_edges = Edges::new(
+            Edges::new(
+                Edges::new("".into(), sql::Dir::Out, "knows".into()).into(),
+                sql::Dir::Out,
+                "person".into(),
+            )
+            .into(),
+            sql::Dir::Out,
+            "knows".into(),
+        )
+        .cond(
+            Cond::new()
+                .left_easy("influencer")
+                .op(Operator::Equal)
+                .right(false.into()),
+        );
+        let delete = DeleteStmt::new()
+            .table("person".into())
+            .cond(where_edges.into())
+            .timeout(sql::Duration::from_secs(5));
+        assert_eq!(
+            delete.to_string().as_str(),
+            "DELETE person WHERE ->knows->person->(knows WHERE influencer = false) TIMEOUT 5s"
+        );
+    }
+
     #[test]
     fn delete_timeout() {
         let delete = DeleteStmt::new()
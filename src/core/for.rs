@@ -0,0 +1,138 @@
+use surrealdb::sql::{statements::ForeachStatement, Block, Value};
+
+use super::{push_entry, sql::Param, BlockStmt, StmtBridge, SubqueryStmt};
+use crate::impl_stmt_bridge;
+
+/// ## create FOR statement
+/// The FOR statement can be used to iterate over the values of an array, and to perform certain actions with those values.
+///
+/// Use `param()` to reference the item in the body
+/// ```
+/// FOR @item IN @iterable {
+///     @block
+/// };
+/// ```
+/// ### example
+/// ```
+/// let person = ForStmt::subquery(
+///     "person",
+///     Stmt::select()
+///         .table("person".into())
+///         .fields(vec![Field::new("id")])
+///         .cond(Cond::new().left("age").op(Operator::MoreThanOrEqual).right(18.into())),
+/// );
+/// let update = Stmt::update()
+///     .table(person.param().into())
+///     .data(UpdateData::set().push(SetField::new("can_vote", None, true)));
+/// let person = person.push(update);
+/// assert_eq!(
+///     person.to_string().as_str(),
+///     "FOR $person IN (SELECT id FROM person WHERE age >= 18) { UPDATE $person SET can_vote = true; }"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForStmt {
+    origin: ForeachStatement,
+}
+
+impl ForStmt {
+    /// ## create FOR statement
+    /// - name : name of the item with or without `$`
+    /// - range : the array to iterate over, such as `[1, 2, 3]` or `$list`
+    pub fn new(name: &str, range: impl Into<Value>) -> Self {
+        ForStmt {
+            origin: ForeachStatement {
+                param: Param::new(name).to_origin(),
+                range: range.into(),
+                block: Block::default(),
+            },
+        }
+    }
+    /// ## FOR @item IN (@statement)
+    /// iterate over the result of a statement, such as SELECT
+    pub fn subquery<S>(name: &str, stmt: S) -> Self
+    where
+        S: SubqueryStmt,
+    {
+        ForStmt::new(name, stmt.into())
+    }
+    /// ## push statement into the body
+    /// any statement which implement BlockStmt can be pushed
+    pub fn push<S>(mut self, stmt: S) -> Self
+    where
+        S: BlockStmt,
+    {
+        push_entry(&mut self.origin.block, stmt.into());
+        self
+    }
+    /// ## get the item param
+    /// which can be used in the body
+    pub fn param(&self) -> Param {
+        Param::new(self.origin.param.0 .0.as_str())
+    }
+}
+
+impl ToString for ForStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(ForStmt, ForeachStatement, Foreach);
+
+#[cfg(test)]
+mod test_for_stmt {
+    use surrealdb::sql::{Operator, Value};
+
+    use crate::core::{
+        create::CreateStmt,
+        ifelse::IfElseStmt,
+        r#break::BreakStmt,
+        r#continue::ContinueStmt,
+        sql::{Cond, CreateData, SetField},
+    };
+
+    use super::ForStmt;
+
+    #[test]
+    fn simple() {
+        let names = ForStmt::new("$name", vec!["Tobie", "Jaime"]);
+        let create = CreateStmt::new()
+            .table("person".into())
+            .data(CreateData::set().push(SetField::new("name", None, names.param())));
+        let names = names.push(create);
+        assert_eq!(
+            names.to_string().as_str(),
+            "FOR $name IN ['Tobie', 'Jaime'] { CREATE person SET name = $name; }"
+        );
+    }
+
+    #[test]
+    fn control() {
+        let numbers = ForStmt::new("num", vec![1, 2, 3, 4, 5]);
+        let num: Value = numbers.param().into();
+        let numbers = numbers
+            .push(
+                IfElseStmt::new(
+                    Cond::new()
+                        .left_value(num.clone())
+                        .op(Operator::Equal)
+                        .right(2.into()),
+                )
+                .then(ContinueStmt::new()),
+            )
+            .push(
+                IfElseStmt::new(
+                    Cond::new()
+                        .left_value(num)
+                        .op(Operator::MoreThan)
+                        .right(3.into()),
+                )
+                .then(BreakStmt::new()),
+            );
+        assert_eq!(
+            numbers.to_string().as_str(),
+            "FOR $num IN [1, 2, 3, 4, 5] {\nIF $num = 2 { CONTINUE; };\nIF $num > 3 { BREAK; };\n}"
+        );
+    }
+}
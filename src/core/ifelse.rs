@@ -0,0 +1,185 @@
+use surrealdb::sql::{statements::IfelseStatement, Statement, Value};
+
+use super::{push_entry, sql::Cond, BlockStmt, StmtBridge};
+use crate::impl_stmt_bridge;
+
+/// ## create IF ELSE statement
+/// The IF ELSE statement can be used as a main statement, or within a parent statement, to return a value depending on whether a condition, or a series of conditions match.
+///
+/// `then()` pushes the statement into the latest branch
+/// ```
+/// IF @condition {
+///     @expression;
+/// }
+/// [ ELSE IF @condition {
+///     @expression;
+/// } ... ]
+/// [ ELSE {
+///     @expression;
+/// } ]
+/// ```
+/// ### example
+/// ```
+/// let ifelse = IfElseStmt::new(
+///     Cond::new()
+///         .left_value(Param::new("user").into())
+///         .op(Operator::Equal)
+///         .right(Value::None),
+/// )
+/// .then(Stmt::create().table("user".into()))
+/// .r#else()
+/// .then(Stmt::select().table(Param::new("user").into()).field_all());
+/// assert_eq!(
+///     ifelse.to_string().as_str(),
+///     "IF $user = NONE { CREATE user; } ELSE { SELECT * FROM $user; }"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct IfElseStmt {
+    origin: IfelseStatement,
+}
+
+impl IfElseStmt {
+    /// ## create IF statement
+    /// - cond : condition of the first branch, `WHERE` is not rendered
+    pub fn new(cond: Cond) -> Self {
+        IfElseStmt {
+            origin: IfelseStatement {
+                exprs: vec![(cond.to_origin().0, new_block())],
+                close: None,
+            },
+        }
+    }
+    /// ## add ELSE IF branch
+    pub fn else_if(mut self, cond: Cond) -> Self {
+        if self.origin.close.is_some() {
+            panic!("ELSE IF can not be added after ELSE");
+        }
+        self.origin.exprs.push((cond.to_origin().0, new_block()));
+        self
+    }
+    /// ## add ELSE branch
+    pub fn r#else(mut self) -> Self {
+        if self.origin.close.is_some() {
+            panic!("ELSE can only be added once");
+        }
+        self.origin.close.replace(new_block());
+        self
+    }
+    /// ## push statement into the latest branch
+    /// any statement which implement BlockStmt can be pushed
+    pub fn then<S>(self, stmt: S) -> Self
+    where
+        S: BlockStmt,
+    {
+        self.push(stmt.into())
+    }
+    /// ## push value into the latest branch
+    /// such as: `IF $age > 18 { 'adult' } ELSE { 'child' }`
+    pub fn then_value(self, value: impl Into<Value>) -> Self {
+        self.push(Statement::Value(value.into()))
+    }
+    fn push(mut self, stmt: Statement) -> Self {
+        let branch = match self.origin.close.as_mut() {
+            Some(close) => close,
+            None => &mut self.origin.exprs.last_mut().unwrap().1,
+        };
+        if let Value::Block(block) = branch {
+            push_entry(block, stmt);
+        }
+        self
+    }
+}
+
+fn new_block() -> Value {
+    Value::Block(Box::default())
+}
+
+impl ToString for IfElseStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(IfElseStmt, IfelseStatement, Ifelse);
+
+#[cfg(test)]
+mod test_ifelse_stmt {
+    use surrealdb::sql::{Operator, Value};
+
+    use crate::core::{
+        create::CreateStmt,
+        select::SelectStmt,
+        sql::{Cond, CreateData, Param},
+    };
+
+    use super::IfElseStmt;
+
+    fn cond(op: Operator, right: Value) -> Cond {
+        Cond::new()
+            .left_value(Param::new("age").into())
+            .op(op)
+            .right(right)
+    }
+
+    #[test]
+    fn simple() {
+        let ifelse = IfElseStmt::new(
+            Cond::new()
+                .left_value(Param::new("user").into())
+                .op(Operator::Equal)
+                .right(Value::None),
+        )
+        .then(
+            CreateStmt::new()
+                .table(("user", "tobie").into())
+                .data(CreateData::set().push(("name", "Tobie").into())),
+        );
+        assert_eq!(
+            ifelse.to_string().as_str(),
+            "IF $user = NONE { CREATE user:tobie SET name = 'Tobie'; }"
+        );
+    }
+
+    #[test]
+    fn branches() {
+        let ifelse = IfElseStmt::new(cond(Operator::MoreThanOrEqual, 18.into()))
+            .then_value("adult")
+            .else_if(cond(Operator::MoreThanOrEqual, 13.into()))
+            .then_value("teenager")
+            .r#else()
+            .then_value("child");
+        assert_eq!(
+            ifelse.to_string().as_str(),
+            "IF $age >= 18 { 'adult' } ELSE IF $age >= 13 { 'teenager' } ELSE { 'child' }"
+        );
+    }
+
+    #[test]
+    fn multiple() {
+        let ifelse = IfElseStmt::new(cond(Operator::LessThan, 18.into()))
+            .then(SelectStmt::new().table("child".into()).field_all())
+            .then(SelectStmt::new().table("parent".into()).field_all());
+        assert_eq!(
+            ifelse.to_string().as_str(),
+            "IF $age < 18 {\nSELECT * FROM child;\nSELECT * FROM parent;\n}"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn else_if_after_else() {
+        let _ = IfElseStmt::new(cond(Operator::LessThan, 18.into()))
+            .r#else()
+            .else_if(cond(Operator::MoreThan, 18.into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn else_twice() {
+        let _ = IfElseStmt::new(cond(Operator::LessThan, 18.into()))
+            .r#else()
+            .then_value("child")
+            .r#else();
+    }
+}
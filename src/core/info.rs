@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{statements::InfoStatement, Base};
+
+use super::{sql::SurrealTable, StmtBridge};
+use crate::impl_stmt_bridge;
+
+/// ## create INFO statement
+/// The INFO command outputs information about the setup of the SurrealDB system.
+/// Decode the result by the matching struct:
+/// - INFO FOR ROOT -> `RootInfo`
+/// - INFO FOR NS -> `NsInfo`
+/// - INFO FOR DB -> `DbInfo`
+/// - INFO FOR SCOPE @scope -> `ScopeInfo`
+/// - INFO FOR TABLE @table -> `TableInfo`
+/// - INFO FOR USER @user -> `String`
+/// ```
+/// INFO FOR [
+///     ROOT
+///     | NS | NAMESPACE
+///     | DB | DATABASE
+///     | SCOPE @scope
+///     | TABLE @table
+///     | USER @user [ ON @level ]
+/// ];
+/// ```
+/// ### example
+/// ```
+/// let info = Stmt::info().db();
+/// assert_eq!(info.to_string().as_str(), "INFO FOR DATABASE");
+/// let mut response = DB.query(info.to_string()).await?;
+/// let db_info: Option<DbInfo> = response.take(0)?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfoStmt {
+    origin: InfoStatement,
+}
+
+impl InfoStmt {
+    /// ## create INFO statement
+    /// default INFO FOR ROOT
+    pub fn new() -> Self {
+        InfoStmt {
+            origin: InfoStatement::Root,
+        }
+    }
+    /// ## INFO FOR ROOT
+    pub fn root(mut self) -> Self {
+        self.origin = InfoStatement::Root;
+        self
+    }
+    /// ## INFO FOR NAMESPACE
+    pub fn ns(mut self) -> Self {
+        self.origin = InfoStatement::Ns;
+        self
+    }
+    /// ## INFO FOR DATABASE
+    pub fn db(mut self) -> Self {
+        self.origin = InfoStatement::Db;
+        self
+    }
+    /// ## INFO FOR SCOPE @scope
+    pub fn scope(mut self, scope: &str) -> Self {
+        self.origin = InfoStatement::Sc(scope.into());
+        self
+    }
+    /// ## INFO FOR TABLE @table
+    pub fn table(mut self, table: SurrealTable) -> Self {
+        let table = match table {
+            SurrealTable::Table(table) => table.0,
+            _ => panic!("INFO FOR TABLE can only use normal table"),
+        };
+        self.origin = InfoStatement::Tb(table.into());
+        self
+    }
+    /// ## INFO FOR USER @user [ ON @level ]
+    pub fn user(mut self, user: &str, base: Option<Base>) -> Self {
+        self.origin = InfoStatement::User(user.into(), base);
+        self
+    }
+}
+
+impl ToString for InfoStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(InfoStmt, InfoStatement, Info);
+
+/// ## result of INFO FOR ROOT
+/// each map is `name -> DEFINE statement`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RootInfo {
+    pub namespaces: BTreeMap<String, String>,
+    pub users: BTreeMap<String, String>,
+}
+
+/// ## result of INFO FOR NS
+/// each map is `name -> DEFINE statement`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NsInfo {
+    pub databases: BTreeMap<String, String>,
+    pub users: BTreeMap<String, String>,
+    pub tokens: BTreeMap<String, String>,
+}
+
+/// ## result of INFO FOR DB
+/// each map is `name -> DEFINE statement`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DbInfo {
+    pub analyzers: BTreeMap<String, String>,
+    pub functions: BTreeMap<String, String>,
+    pub models: BTreeMap<String, String>,
+    pub params: BTreeMap<String, String>,
+    pub scopes: BTreeMap<String, String>,
+    pub tables: BTreeMap<String, String>,
+    pub tokens: BTreeMap<String, String>,
+    pub users: BTreeMap<String, String>,
+}
+
+/// ## result of INFO FOR SCOPE
+/// each map is `name -> DEFINE statement`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScopeInfo {
+    pub tokens: BTreeMap<String, String>,
+}
+
+/// ## result of INFO FOR TABLE
+/// each map is `name -> DEFINE statement`, `lives` is `id -> LIVE SELECT statement`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableInfo {
+    pub events: BTreeMap<String, String>,
+    pub fields: BTreeMap<String, String>,
+    pub indexes: BTreeMap<String, String>,
+    pub lives: BTreeMap<String, String>,
+    /// foreign tables (views) of the table
+    pub tables: BTreeMap<String, String>,
+}
+
+#[cfg(test)]
+mod test_info_stmt {
+    use serde_json::json;
+    use surrealdb::sql::Base;
+
+    use super::{DbInfo, InfoStmt, TableInfo};
+
+    #[test]
+    fn simple() {
+        assert_eq!(InfoStmt::new().to_string().as_str(), "INFO FOR ROOT");
+        assert_eq!(
+            InfoStmt::new().ns().to_string().as_str(),
+            "INFO FOR NAMESPACE"
+        );
+        assert_eq!(
+            InfoStmt::new().db().to_string().as_str(),
+            "INFO FOR DATABASE"
+        );
+        assert_eq!(
+            InfoStmt::new().scope("account").to_string().as_str(),
+            "INFO FOR SCOPE account"
+        );
+        assert_eq!(
+            InfoStmt::new().table("user".into()).to_string().as_str(),
+            "INFO FOR TABLE user"
+        );
+        assert_eq!(
+            InfoStmt::new()
+                .user("root", Some(Base::Root))
+                .to_string()
+                .as_str(),
+            "INFO FOR USER root ON ROOT"
+        );
+    }
+
+    #[test]
+    fn db_info() {
+        let info: DbInfo = serde_json::from_value(json!({
+            "analyzers": {},
+            "functions": {
+                "greet": "DEFINE FUNCTION fn::greet($name: string) { 'Hello, ' + $name } PERMISSIONS FULL"
+            },
+            "params": {},
+            "scopes": {},
+            "tables": {
+                "user": "DEFINE TABLE user SCHEMAFULL PERMISSIONS NONE"
+            },
+            "tokens": {},
+            "users": {}
+        }))
+        .unwrap();
+        assert!(info.models.is_empty());
+        assert_eq!(
+            info.tables.get("user").unwrap().as_str(),
+            "DEFINE TABLE user SCHEMAFULL PERMISSIONS NONE"
+        );
+        assert_eq!(info.functions.len(), 1);
+    }
+
+    #[test]
+    fn table_info() {
+        let info: TableInfo = serde_json::from_value(json!({
+            "events": {},
+            "fields": {
+                "email": "DEFINE FIELD email ON user TYPE string PERMISSIONS FULL"
+            },
+            "indexes": {
+                "userEmailIndex": "DEFINE INDEX userEmailIndex ON user FIELDS email UNIQUE"
+            },
+            "lives": {},
+            "tables": {}
+        }))
+        .unwrap();
+        assert_eq!(info.fields.len(), 1);
+        assert!(info.indexes.contains_key("userEmailIndex"));
+    }
+}
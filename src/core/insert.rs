@@ -159,7 +159,7 @@ impl ToString for InsertStmt {
     }
 }
 
-impl_stmt_bridge!(InsertStmt, InsertStatement);
+impl_stmt_bridge!(InsertStmt, InsertStatement, Insert);
 
 #[cfg(test)]
 mod test_insert_stmt {
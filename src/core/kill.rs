@@ -0,0 +1,74 @@
+use surrealdb::sql::{statements::KillStatement, Param, Uuid, Value};
+
+use super::StmtBridge;
+use crate::impl_stmt_bridge;
+
+/// ## create KILL statement
+/// The KILL statement is used to terminate a running live query.
+/// ```
+/// KILL @value;
+/// ```
+/// ### example
+/// ```
+/// let kill = KillStmt::new(Uuid::try_from("0189d6e3-8eac-703a-9a48-d9faa78b44b9").unwrap());
+/// assert_eq!(
+///     kill.to_string().as_str(),
+///     "KILL '0189d6e3-8eac-703a-9a48-d9faa78b44b9'"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct KillStmt {
+    origin: KillStatement,
+}
+
+impl KillStmt {
+    /// ## create KILL statement
+    /// - id : the id of the live query, which is returned by LIVE SELECT
+    pub fn new(id: impl Into<Uuid>) -> Self {
+        KillStmt {
+            origin: KillStatement {
+                id: Value::Uuid(id.into()),
+            },
+        }
+    }
+    /// ## KILL $@param
+    /// the param should resolve to the id of the live query
+    pub fn param(name: &str) -> Self {
+        KillStmt {
+            origin: KillStatement {
+                id: Value::Param(Param::from(name.trim_start_matches('$'))),
+            },
+        }
+    }
+}
+
+impl ToString for KillStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(KillStmt, KillStatement, Kill);
+
+#[cfg(test)]
+mod test_kill_stmt {
+    use surrealdb::sql::Uuid;
+
+    use super::KillStmt;
+
+    #[test]
+    fn simple() {
+        let id = Uuid::try_from("0189d6e3-8eac-703a-9a48-d9faa78b44b9").unwrap();
+        let kill = KillStmt::new(id);
+        assert_eq!(
+            kill.to_string().as_str(),
+            "KILL '0189d6e3-8eac-703a-9a48-d9faa78b44b9'"
+        );
+    }
+
+    #[test]
+    fn param() {
+        let kill = KillStmt::param("$live_id");
+        assert_eq!(kill.to_string().as_str(), "KILL $live_id");
+    }
+}
@@ -0,0 +1,137 @@
+use surrealdb::sql::{statements::SetStatement, Value};
+
+use super::{sql::Param, StmtBridge, SubqueryStmt};
+use crate::impl_stmt_bridge;
+
+/// ## create LET statement
+/// The LET statement sets and stores a value which can then be used in a subsequent query.
+/// Use `param()` to reference the value in the following statements
+/// ```
+/// LET $@parameter = @value;
+/// ```
+/// ### example
+/// ```
+/// let adults = LetStmt::subquery(
+///     "adults",
+///     Stmt::select()
+///         .table("person".into())
+///         .field_all()
+///         .cond(Cond::new().left("age").op(Operator::MoreThan).right(18.into())),
+/// );
+/// assert_eq!(
+///     adults.to_string().as_str(),
+///     "LET $adults = (SELECT * FROM person WHERE age > 18)"
+/// );
+/// let select = Stmt::select().table(adults.param().into()).field_all();
+/// assert_eq!(select.to_string().as_str(), "SELECT * FROM $adults");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LetStmt {
+    origin: SetStatement,
+}
+
+impl LetStmt {
+    /// ## create LET statement
+    /// - name : name of the param with or without `$`
+    /// - value : the value of the param
+    pub fn new(name: &str, value: impl Into<Value>) -> Self {
+        LetStmt {
+            origin: SetStatement {
+                name: name.trim_start_matches('$').to_string(),
+                what: value.into(),
+            },
+        }
+    }
+    /// ## LET $@parameter = (@statement)
+    /// store the result of a statement, such as SELECT or CREATE
+    pub fn subquery<S>(name: &str, stmt: S) -> Self
+    where
+        S: SubqueryStmt,
+    {
+        LetStmt::new(name, stmt.into())
+    }
+    /// ## set the value of the param
+    pub fn value(mut self, value: impl Into<Value>) -> Self {
+        self.origin.what = value.into();
+        self
+    }
+    /// name of the param without `$`
+    pub fn name(&self) -> &str {
+        self.origin.name.as_str()
+    }
+    /// ## get the param
+    /// which can be used in the subsequent statements
+    pub fn param(&self) -> Param {
+        Param::new(self.name())
+    }
+}
+
+impl ToString for LetStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(LetStmt, SetStatement, Set);
+
+#[cfg(test)]
+mod test_let_stmt {
+    use surrealdb::sql::Operator;
+
+    use crate::core::{
+        select::SelectStmt,
+        sql::{Cond, SetField, UpdateData},
+        update::UpdateStmt,
+    };
+
+    use super::LetStmt;
+
+    #[test]
+    fn simple() {
+        let name = LetStmt::new("$name", "tobie");
+        let age = LetStmt::new("age", 18).value(20);
+        assert_eq!(name.name(), "name");
+        assert_eq!(name.to_string().as_str(), "LET $name = 'tobie'");
+        assert_eq!(age.to_string().as_str(), "LET $age = 20");
+    }
+
+    #[test]
+    fn subquery() {
+        let adults = LetStmt::subquery(
+            "adults",
+            SelectStmt::new().table("person".into()).field_all().cond(
+                Cond::new()
+                    .left("age")
+                    .op(Operator::MoreThan)
+                    .right(18.into()),
+            ),
+        );
+        assert_eq!(
+            adults.to_string().as_str(),
+            "LET $adults = (SELECT * FROM person WHERE age > 18)"
+        );
+    }
+
+    #[test]
+    fn param() {
+        let company = LetStmt::new("company", "SurrealDB").param();
+        let adults = LetStmt::subquery(
+            "adults",
+            SelectStmt::new().table("person".into()).field_all(),
+        )
+        .param();
+        let update = UpdateStmt::new()
+            .table(adults.into())
+            .data(UpdateData::set().push(SetField::new("company", None, company.clone())))
+            .cond(
+                Cond::new()
+                    .left("company")
+                    .op(Operator::NotEqual)
+                    .right(company.into()),
+            );
+        assert_eq!(
+            update.to_string().as_str(),
+            "UPDATE $adults SET company = $company WHERE company != $company"
+        );
+    }
+}
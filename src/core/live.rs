@@ -0,0 +1,262 @@
+use surrealdb::sql::{self, statements::LiveStatement, Fetch, Fetchs, Fields};
+
+use super::{
+    select::SelectStmt,
+    sql::{Cond, Field, SurrealTable},
+    StmtBridge,
+};
+use crate::impl_stmt_bridge;
+
+/// ## create LIVE SELECT statement
+/// The LIVE SELECT statement can be used to initiate a real-time selection from a table,
+/// including the option to apply filters.
+/// In DIFF mode, the notifications are JSON Patch operations instead of the whole records
+/// ```
+/// LIVE SELECT [ [ VALUE ] @fields [ AS @alias ] | DIFF ]
+///     FROM @targets
+///     [ WHERE @conditions ]
+///     [ FETCH @fields ... ]
+/// ;
+/// ```
+/// ### example
+/// ```
+/// let live = LiveSelectStmt::new()
+///     .table("person".into())
+///     .diff()
+///     .cond(Cond::new().left("age").op(Operator::MoreThan).right(18.into()));
+/// assert_eq!(
+///     live.to_string().as_str(),
+///     "LIVE SELECT DIFF FROM person WHERE age > 18"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveSelectStmt {
+    origin: LiveStatement,
+    /// DIFF is stored as empty fields in `LiveStatement`
+    diff: bool,
+}
+
+impl LiveSelectStmt {
+    /// ## create LIVE SELECT statement
+    /// default `LIVE SELECT *`
+    pub fn new() -> Self {
+        LiveSelectStmt {
+            origin: LiveStatement::default(),
+            diff: false,
+        }
+        .field_all()
+    }
+    /// ## set FROM
+    pub fn table(mut self, table: SurrealTable) -> Self {
+        self.origin.what = table.into();
+        self
+    }
+    /// ## set fields
+    /// ### panic
+    /// fields can not be empty, use `diff()` for DIFF mode
+    pub fn fields(mut self, fields: Vec<Field>) -> Self {
+        if fields.is_empty() {
+            panic!("fields of LIVE SELECT can not be empty");
+        }
+        let fields = fields
+            .into_iter()
+            .map(|x| x.to_origin())
+            .collect::<Vec<sql::Field>>();
+        self.origin.expr = Fields(fields, false);
+        self.diff = false;
+        self
+    }
+    pub fn field_all(self) -> Self {
+        self.fields(vec![Field::all()])
+    }
+    /// ## set keyword DIFF
+    /// receive JSON Patch operations of the changes
+    pub fn diff(mut self) -> Self {
+        self.origin.expr = Fields::default();
+        self.diff = true;
+        self
+    }
+    /// is the statement in DIFF mode
+    pub fn is_diff(&self) -> bool {
+        self.diff
+    }
+    pub fn cond(mut self, cond: Cond) -> Self {
+        self.origin.cond.replace(cond.to_origin());
+        self
+    }
+    pub fn fetch(mut self, fetchs: Vec<Field>) -> Self {
+        let fetchs = fetchs
+            .into_iter()
+            .map(|x| Fetch(x.to_idiom()))
+            .collect::<Vec<Fetch>>();
+        self.origin.fetch.replace(Fetchs(fetchs));
+        self
+    }
+}
+
+impl ToString for LiveSelectStmt {
+    fn to_string(&self) -> String {
+        if self.is_diff() {
+            live_diff(&self.origin)
+        } else {
+            self.origin.to_string()
+        }
+    }
+}
+
+impl_stmt_bridge!(LiveSelectStmt, LiveStatement, Live);
+
+/// DIFF is an empty Fields, which is rendered as nothing by surrealdb
+pub(crate) fn live_diff(live: &LiveStatement) -> String {
+    let mut diff = format!("LIVE SELECT DIFF FROM {}", live.what);
+    if let Some(cond) = &live.cond {
+        diff.push_str(&format!(" {}", cond));
+    }
+    if let Some(fetch) = &live.fetch {
+        diff.push_str(&format!(" {}", fetch));
+    }
+    diff
+}
+
+/// ## convert SELECT statement to LIVE SELECT statement
+/// fields, the target, WHERE and FETCH are kept
+/// ### panic
+/// - the SELECT statement must have exactly one target
+/// - clauses which LIVE SELECT does not support, such as LIMIT and ORDER BY, can not be set
+impl From<SelectStmt> for LiveSelectStmt {
+    fn from(value: SelectStmt) -> Self {
+        let select = value.to_origin();
+        let unsupported = [
+            ("OMIT", select.omit.is_some()),
+            ("ONLY", select.only),
+            ("WITH", select.with.is_some()),
+            ("SPLIT", select.split.is_some()),
+            ("GROUP", select.group.is_some()),
+            ("ORDER BY", select.order.is_some()),
+            ("LIMIT", select.limit.is_some()),
+            ("START", select.start.is_some()),
+            ("VERSION", select.version.is_some()),
+            ("TIMEOUT", select.timeout.is_some()),
+            ("PARALLEL", select.parallel),
+            ("EXPLAIN", select.explain.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(clause, used)| used.then_some(clause))
+        .collect::<Vec<&str>>();
+        if !unsupported.is_empty() {
+            panic!("{} cannot be used in LIVE SELECT", unsupported.join(", "));
+        }
+        let mut what = select.what.0;
+        if what.len() != 1 {
+            panic!(
+                "LIVE SELECT needs exactly one target, but got {}",
+                what.len()
+            );
+        }
+        let mut live = LiveSelectStmt::new();
+        if !select.expr.0.is_empty() {
+            live.origin.expr = select.expr;
+        }
+        live.origin.what = what.remove(0);
+        live.origin.cond = select.cond;
+        live.origin.fetch = select.fetch;
+        live
+    }
+}
+
+#[cfg(test)]
+mod test_live_select_stmt {
+    use surrealdb::sql::Operator;
+
+    use crate::core::{
+        select::SelectStmt,
+        sql::{Cond, Field},
+    };
+
+    use super::LiveSelectStmt;
+
+    #[test]
+    fn simple() {
+        let live = LiveSelectStmt::new().table("person".into());
+        let fields = LiveSelectStmt::new()
+            .table("person".into())
+            .fields(vec![Field::new("name"), Field::new("age")])
+            .fetch(vec![Field::new("friends")]);
+        assert_eq!(live.to_string().as_str(), "LIVE SELECT * FROM person");
+        assert_eq!(
+            fields.to_string().as_str(),
+            "LIVE SELECT name, age FROM person FETCH friends"
+        );
+    }
+
+    #[test]
+    fn diff() {
+        let live = LiveSelectStmt::new()
+            .table("person".into())
+            .diff()
+            .cond(
+                Cond::new()
+                    .left("age")
+                    .op(Operator::MoreThan)
+                    .right(18.into()),
+            )
+            .fetch(vec![Field::new("friends")]);
+        assert!(live.is_diff());
+        assert!(!live.clone().field_all().is_diff());
+        assert_eq!(
+            live.to_string().as_str(),
+            "LIVE SELECT DIFF FROM person WHERE age > 18 FETCH friends"
+        );
+    }
+
+    #[test]
+    fn from_select() {
+        let select = SelectStmt::new()
+            .table("person".into())
+            .fields(vec![Field::new("name")])
+            .cond(
+                Cond::new()
+                    .left("age")
+                    .op(Operator::MoreThan)
+                    .right(18.into()),
+            );
+        let live = LiveSelectStmt::from(select);
+        assert_eq!(
+            live.to_string().as_str(),
+            "LIVE SELECT name FROM person WHERE age > 18"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_fields() {
+        let _ = LiveSelectStmt::new().table("person".into()).fields(vec![]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_select_without_target() {
+        let _ = LiveSelectStmt::from(SelectStmt::new().field_all());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_select_with_targets() {
+        let _ = LiveSelectStmt::from(
+            SelectStmt::new()
+                .tables(vec!["person".into(), "user".into()])
+                .field_all(),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_select_with_limit() {
+        let _ = LiveSelectStmt::from(
+            SelectStmt::new()
+                .table("person".into())
+                .field_all()
+                .limit(10),
+        );
+    }
+}
@@ -1,14 +1,34 @@
+mod r#break;
+mod r#continue;
 mod create;
+mod define;
 mod delete;
+mod r#for;
+mod ifelse;
+mod info;
 mod insert;
+mod kill;
+mod r#let;
+mod live;
+mod relate;
+mod remove;
+mod r#return;
 mod select;
+mod show;
+mod sleep;
 pub mod sql;
 mod stmt;
+mod throw;
+mod transaction;
 mod update;
 mod r#use;
 
+pub use info::{DbInfo, NsInfo, RootInfo, ScopeInfo, TableInfo};
+pub use show::{Change, ChangeSet};
 pub use stmt::Stmt;
 
+use surrealdb::sql::{statements::RemoveStatement, Block, Statement, Subquery, Value};
+
 /// ## statement bridge
 /// Implement a statement bridge that endows statements with the ability to convert them into original statements
 pub trait StmtBridge {
@@ -19,10 +39,33 @@ pub trait StmtBridge {
     fn origin(&self) -> &Self::OriginType;
 }
 
+/// ## statement in block
+/// marks the statements which can be used in a block,
+/// such as the body of `DEFINE FUNCTION`, `IF ELSE` and `FOR`
+///
+/// statements such as USE, INFO, LIVE, KILL, SLEEP and SHOW are not allowed in a block
+/// ```compile_fail
+/// use surreal_use::core::Stmt;
+///
+/// let _ = Stmt::r#for("item", vec![1, 2, 3]).push(Stmt::r#use());
+/// ```
+pub trait BlockStmt: StmtBridge + Into<Statement> {}
+
+/// ## statement as subquery
+/// marks the statements which can be converted to subquery,
+/// such as `(SELECT * FROM person)` in LET, FOR, RETURN and the THEN of DEFINE EVENT
+/// ```compile_fail
+/// use surreal_use::core::Stmt;
+///
+/// let _ = Stmt::define_event("email", "user".into()).then(Stmt::r#use());
+/// ```
+pub trait SubqueryStmt: StmtBridge + Into<Value> {}
+
 /// ## macro for StmtBridge
 /// Macro for implementing statement bridge
 ///
-/// Need to pass in the extended statement type and the original statement type
+/// Need to pass in the extended statement type and the original statement type,
+/// optionally followed by the variant of `surrealdb::sql::Statement` which wraps the original statement
 /// ### The generated syntax is as follows
 /// ```
 /// impl StmtBridge for UseStmt {
@@ -35,6 +78,14 @@ pub trait StmtBridge {
 ///         &self.origin
 ///     }
 /// }
+/// // impl_stmt_bridge!(UseStmt, UseStatement, Use) also generates:
+/// impl From<UseStmt> for surrealdb::sql::Statement {
+///     fn from(value: UseStmt) -> Self {
+///         surrealdb::sql::Statement::Use(value.to_origin())
+///     }
+/// }
+/// // nested statements such as DEFINE TABLE use:
+/// // impl_stmt_bridge!(DefineTableStmt, DefineTableStatement, Define(DefineStatement::Table))
 /// ```
 #[macro_export]
 macro_rules! impl_stmt_bridge {
@@ -50,4 +101,150 @@ macro_rules! impl_stmt_bridge {
             }
         }
     };
+    ($stmt:ty , $origin:ty , $variant:ident) => {
+        $crate::impl_stmt_bridge!($stmt, $origin);
+
+        impl From<$stmt> for surrealdb::sql::Statement {
+            fn from(value: $stmt) -> Self {
+                surrealdb::sql::Statement::$variant(value.to_origin())
+            }
+        }
+    };
+    ($stmt:ty , $origin:ty , $variant:ident($outer:ident :: $inner:ident)) => {
+        $crate::impl_stmt_bridge!($stmt, $origin);
+
+        impl From<$stmt> for surrealdb::sql::Statement {
+            fn from(value: $stmt) -> Self {
+                surrealdb::sql::Statement::$variant(surrealdb::sql::statements::$outer::$inner(
+                    value.to_origin(),
+                ))
+            }
+        }
+    };
 }
+
+/// ## convert statement to subquery
+/// such as `(CREATE person SET name = 'Matt')`,
+/// which can be used where a Value is accepted
+pub(crate) fn to_subquery(stmt: Statement) -> Value {
+    let subquery = match stmt {
+        Statement::Value(v) => Subquery::Value(v),
+        Statement::Ifelse(v) => Subquery::Ifelse(v),
+        Statement::Output(v) => Subquery::Output(v),
+        Statement::Select(v) => Subquery::Select(v),
+        Statement::Create(v) => Subquery::Create(v),
+        Statement::Update(v) => Subquery::Update(v),
+        Statement::Delete(v) => Subquery::Delete(v),
+        Statement::Relate(v) => Subquery::Relate(v),
+        Statement::Insert(v) => Subquery::Insert(v),
+        Statement::Define(v) => Subquery::Define(v),
+        Statement::Remove(v) => Subquery::Remove(v),
+        _ => panic!("{} cannot be converted to subquery", stmt),
+    };
+    Value::Subquery(Box::new(subquery))
+}
+
+/// ## render statement
+/// such as the statements joined in a transaction,
+/// fix the ones which surrealdb renders but can not parse back
+pub(crate) fn render_stmt(stmt: &Statement) -> String {
+    match stmt {
+        Statement::Remove(RemoveStatement::Param(param)) => remove::remove_param(param),
+        Statement::Live(live) if live.expr.0.is_empty() => live::live_diff(live),
+        stmt => stmt.to_string(),
+    }
+}
+
+/// ## push statement into block
+/// such as the body of `DEFINE FUNCTION fn::greet() { ... }`
+///
+/// `surrealdb::sql::block::Entry` is not exported,
+/// but it shares the serialized form of Statement, so Entry is built from it
+pub(crate) fn push_entry(block: &mut Block, stmt: Statement) {
+    let entries = match stmt {
+        Statement::Value(v) => Block::from(v),
+        _ => match serde_json::to_value(vec![&stmt]).and_then(serde_json::from_value) {
+            Ok(entries) => entries,
+            Err(_) => panic!("{} cannot be used in a block", stmt),
+        },
+    };
+    block.0.extend(entries.0);
+}
+
+/// ## convert statements to subquery value
+/// so that they can be used in conditions and SET fields, such as:
+/// `WHERE id IN (SELECT VALUE person FROM vip)`
+macro_rules! impl_subquery {
+    ($($stmt:ty),* $(,)?) => {
+        $(
+            impl From<$stmt> for Value {
+                fn from(value: $stmt) -> Self {
+                    to_subquery(value.into())
+                }
+            }
+
+            impl SubqueryStmt for $stmt {}
+        )*
+    };
+}
+
+impl_subquery!(
+    select::SelectStmt,
+    create::CreateStmt,
+    update::UpdateStmt,
+    delete::DeleteStmt,
+    relate::RelateStmt,
+    insert::InsertStmt,
+    ifelse::IfElseStmt,
+    r#return::ReturnStmt,
+    remove::RemoveStmt,
+    define::DefineTableStmt,
+    define::DefineFieldStmt,
+    define::DefineIndexStmt,
+    define::DefineEventStmt,
+    define::DefineFunctionStmt,
+    define::DefineAnalyzerStmt,
+    define::DefineScopeStmt,
+    define::DefineUserStmt,
+    define::DefineTokenStmt,
+    define::DefineNamespaceStmt,
+    define::DefineDatabaseStmt,
+    define::DefineParamStmt,
+);
+
+macro_rules! impl_block_stmt {
+    ($($stmt:ty),* $(,)?) => {
+        $(
+            impl BlockStmt for $stmt {}
+        )*
+    };
+}
+
+impl_block_stmt!(
+    r#let::LetStmt,
+    ifelse::IfElseStmt,
+    select::SelectStmt,
+    create::CreateStmt,
+    update::UpdateStmt,
+    delete::DeleteStmt,
+    relate::RelateStmt,
+    insert::InsertStmt,
+    r#return::ReturnStmt,
+    remove::RemoveStmt,
+    throw::ThrowStmt,
+    r#break::BreakStmt,
+    r#continue::ContinueStmt,
+    r#for::ForStmt,
+    define::DefineTableStmt,
+    define::DefineFieldStmt,
+    define::DefineIndexStmt,
+    define::DefineEventStmt,
+    define::DefineFunctionStmt,
+    define::DefineAnalyzerStmt,
+    define::DefineScopeStmt,
+    define::DefineUserStmt,
+    define::DefineTokenStmt,
+    define::DefineNamespaceStmt,
+    define::DefineDatabaseStmt,
+    define::DefineParamStmt,
+);
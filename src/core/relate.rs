@@ -0,0 +1,181 @@
+use surrealdb::sql::{statements::RelateStatement, Duration, Output, Timeout, Value};
+
+use crate::impl_stmt_bridge;
+
+use super::sql::{CreateData, SurrealTable};
+
+use super::StmtBridge;
+
+/// ## create RELATE statement
+/// The RELATE statement can be used to generate graph edges between two records in the database.
+///
+/// `RELATE @from -> @edge -> @to`
+/// ### example
+/// ```
+/// let relate = RelateStmt::new()
+///     .from(("person", "l19zjikkw1p1h9o6ixrg").into())
+///     .edge("wrote".into())
+///     .to(("article", "8nkk6uj4yprt49z7y3zm").into())
+///     .data(CreateData::set().push(SetField::new("time.written", None, "2024-01-28")));
+/// assert_eq!(
+///     relate.to_string().as_str(),
+///     "RELATE person:l19zjikkw1p1h9o6ixrg -> wrote -> article:8nkk6uj4yprt49z7y3zm SET time.written = '2024-01-28'"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelateStmt {
+    origin: RelateStatement,
+}
+
+impl RelateStmt {
+    pub fn new() -> Self {
+        RelateStmt {
+            origin: RelateStatement::default(),
+        }
+    }
+    /// ## set keyword ONLY
+    /// only a single edge object will be returned instead of an array
+    pub fn only(mut self) -> Self {
+        self.origin.only = true;
+        self
+    }
+    /// ## set the record which the edge starts from
+    pub fn from(mut self, from: SurrealTable) -> Self {
+        self.origin.from = from.into();
+        self
+    }
+    /// ## set multiple records which the edges start from
+    /// ### example
+    /// ```
+    /// let relate = RelateStmt::new()
+    ///     .froms(vec![("person", "tobie").into(), ("person", "jaime").into()])
+    ///     .edge("know".into())
+    ///     .to(("person", "matt").into());
+    /// assert_eq!(
+    ///     relate.to_string().as_str(),
+    ///     "RELATE [person:tobie, person:jaime] -> know -> person:matt"
+    /// );
+    /// ```
+    pub fn froms(mut self, froms: Vec<SurrealTable>) -> Self {
+        self.origin.from = to_array(froms);
+        self
+    }
+    /// ## set the edge table
+    pub fn edge(mut self, edge: SurrealTable) -> Self {
+        self.origin.kind = edge.into();
+        self
+    }
+    /// ## set the record which the edge points to
+    pub fn to(mut self, to: SurrealTable) -> Self {
+        self.origin.with = to.into();
+        self
+    }
+    /// ## set multiple records which the edges point to
+    pub fn tos(mut self, tos: Vec<SurrealTable>) -> Self {
+        self.origin.with = to_array(tos);
+        self
+    }
+    /// ## set keyword UNIQUE
+    /// prevent the same edge from being created twice between two records
+    pub fn unique(mut self) -> Self {
+        self.origin.uniq = true;
+        self
+    }
+    /// ## set data of the edge
+    /// - SET
+    /// - CONTENT
+    pub fn data(mut self, data: CreateData) -> Self {
+        self.origin.data.replace(data.into());
+        self
+    }
+    pub fn output(mut self, output: Output) -> Self {
+        self.origin.output.replace(output);
+        self
+    }
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.origin.timeout = Some(Timeout(timeout));
+        self
+    }
+    /// ## Set whether statements can be processed in parallel
+    /// default close
+    pub fn parallel(mut self) -> Self {
+        self.origin.parallel = true;
+        self
+    }
+}
+
+impl ToString for RelateStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(RelateStmt, RelateStatement, Relate);
+
+/// `Vec<SurrealTable>` -> `Value::Array`
+fn to_array(tables: Vec<SurrealTable>) -> Value {
+    Value::Array(
+        tables
+            .into_iter()
+            .map(Value::from)
+            .collect::<Vec<Value>>()
+            .into(),
+    )
+}
+
+#[cfg(test)]
+mod test_relate_stmt {
+    use serde::Serialize;
+    use surrealdb::sql::{Duration, Output};
+
+    use crate::core::sql::{CreateData, SetField};
+
+    use super::RelateStmt;
+
+    #[test]
+    fn simple() {
+        let relate = RelateStmt::new()
+            .from(("person", "l19zjikkw1p1h9o6ixrg").into())
+            .edge("wrote".into())
+            .to(("article", "8nkk6uj4yprt49z7y3zm").into())
+            .data(CreateData::set().push(SetField::new("time.written", None, "2024-01-28")));
+        assert_eq!(
+            relate.to_string().as_str(),
+            "RELATE person:l19zjikkw1p1h9o6ixrg -> wrote -> article:8nkk6uj4yprt49z7y3zm SET time.written = '2024-01-28'"
+        );
+    }
+
+    #[test]
+    fn multiple() {
+        let relate = RelateStmt::new()
+            .froms(vec![("person", "tobie").into(), ("person", "jaime").into()])
+            .edge("know".into())
+            .tos(vec![("person", "matt").into()])
+            .unique();
+        assert_eq!(
+            relate.to_string().as_str(),
+            "RELATE [person:tobie, person:jaime] -> know -> [person:matt] UNIQUE"
+        );
+    }
+
+    #[test]
+    fn content() {
+        #[derive(Debug, Clone, Serialize)]
+        struct Like {
+            rating: u8,
+        }
+        let relate = RelateStmt::new()
+            .only()
+            .from(("user", "tobie").into())
+            .edge("like".into())
+            .to(("post", "one").into())
+            .data(CreateData::content(Like { rating: 5 }))
+            .output(Output::After)
+            .timeout(Duration::from_secs(5))
+            .parallel();
+        assert_eq!(
+            relate.to_string().as_str(),
+            "RELATE ONLY user:tobie -> like -> post:one CONTENT { rating: 5 } RETURN AFTER TIMEOUT 5s PARALLEL"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use surrealdb::sql::{
+    statements::{
+        RemoveAnalyzerStatement, RemoveDatabaseStatement, RemoveEventStatement,
+        RemoveFieldStatement, RemoveFunctionStatement, RemoveIndexStatement,
+        RemoveNamespaceStatement, RemoveParamStatement, RemoveScopeStatement, RemoveStatement,
+        RemoveTableStatement, RemoveTokenStatement, RemoveUserStatement,
+    },
+    Base, Ident,
+};
+
+use super::{
+    sql::{Field, SurrealTable},
+    StmtBridge,
+};
+use crate::impl_stmt_bridge;
+
+/// ## create REMOVE statement
+/// choose what to remove, then get `RemoveStmt`
+/// ```
+/// REMOVE [
+///     NAMESPACE @name
+///     | DATABASE @name
+///     | USER @name ON [ ROOT | NAMESPACE | DATABASE ]
+///     | TOKEN @name ON [ NAMESPACE | DATABASE | SCOPE @scope ]
+///     | SCOPE @name
+///     | EVENT @name ON [ TABLE ] @table
+///     | FUNCTION fn::@name
+///     | FIELD @name ON [ TABLE ] @table
+///     | INDEX @name ON [ TABLE ] @table
+///     | ANALYZER @name
+///     | PARAM $@name
+///     | TABLE @name
+/// ]
+/// ```
+/// ### example
+/// ```
+/// let remove = Stmt::remove().field("address.city", "user".into());
+/// assert_eq!(remove.to_string().as_str(), "REMOVE FIELD address.city ON user");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Remove;
+
+impl Remove {
+    pub fn new() -> Self {
+        Remove
+    }
+    /// ## REMOVE NAMESPACE @name
+    pub fn ns(self, name: &str) -> RemoveStmt {
+        RemoveStatement::Namespace(RemoveNamespaceStatement { name: name.into() }).into()
+    }
+    /// ## REMOVE DATABASE @name
+    pub fn db(self, name: &str) -> RemoveStmt {
+        RemoveStatement::Database(RemoveDatabaseStatement { name: name.into() }).into()
+    }
+    /// ## REMOVE USER @name ON [ ROOT | NAMESPACE | DATABASE ]
+    pub fn user(self, name: &str, base: Base) -> RemoveStmt {
+        RemoveStatement::User(RemoveUserStatement {
+            name: name.into(),
+            base,
+        })
+        .into()
+    }
+    /// ## REMOVE TOKEN @name ON [ NAMESPACE | DATABASE | SCOPE @scope ]
+    pub fn token(self, name: &str, base: Base) -> RemoveStmt {
+        RemoveStatement::Token(RemoveTokenStatement {
+            name: name.into(),
+            base,
+        })
+        .into()
+    }
+    /// ## REMOVE SCOPE @name
+    pub fn scope(self, name: &str) -> RemoveStmt {
+        RemoveStatement::Scope(RemoveScopeStatement { name: name.into() }).into()
+    }
+    /// ## REMOVE EVENT @name ON @table
+    pub fn event(self, name: &str, table: SurrealTable) -> RemoveStmt {
+        RemoveStatement::Event(RemoveEventStatement {
+            name: name.into(),
+            what: table_name(table),
+        })
+        .into()
+    }
+    /// ## REMOVE FUNCTION fn::@name
+    /// name with or without `fn::`
+    pub fn function(self, name: &str) -> RemoveStmt {
+        RemoveStatement::Function(RemoveFunctionStatement {
+            name: name.trim_start_matches("fn::").into(),
+        })
+        .into()
+    }
+    /// ## REMOVE FIELD @field ON @table
+    pub fn field(self, field: impl Into<Field>, table: SurrealTable) -> RemoveStmt {
+        RemoveStatement::Field(RemoveFieldStatement {
+            name: field.into().to_idiom(),
+            what: table_name(table),
+        })
+        .into()
+    }
+    /// ## REMOVE INDEX @name ON @table
+    pub fn index(self, name: &str, table: SurrealTable) -> RemoveStmt {
+        RemoveStatement::Index(RemoveIndexStatement {
+            name: name.into(),
+            what: table_name(table),
+        })
+        .into()
+    }
+    /// ## REMOVE ANALYZER @name
+    pub fn analyzer(self, name: &str) -> RemoveStmt {
+        RemoveStatement::Analyzer(RemoveAnalyzerStatement { name: name.into() }).into()
+    }
+    /// ## REMOVE PARAM $@name
+    /// name with or without `$`
+    pub fn param(self, name: &str) -> RemoveParamStmt {
+        RemoveParamStmt {
+            origin: RemoveParamStatement {
+                name: name.trim_start_matches('$').into(),
+            },
+        }
+    }
+    /// ## REMOVE TABLE @name
+    pub fn table(self, table: SurrealTable) -> RemoveStmt {
+        RemoveStatement::Table(RemoveTableStatement {
+            name: table_name(table),
+        })
+        .into()
+    }
+}
+
+/// ## REMOVE statement
+/// build by `Stmt::remove()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoveStmt {
+    origin: RemoveStatement,
+}
+
+impl From<RemoveStatement> for RemoveStmt {
+    fn from(value: RemoveStatement) -> Self {
+        RemoveStmt { origin: value }
+    }
+}
+
+impl ToString for RemoveStmt {
+    fn to_string(&self) -> String {
+        match &self.origin {
+            RemoveStatement::Param(param) => remove_param(param),
+            origin => origin.to_string(),
+        }
+    }
+}
+
+impl_stmt_bridge!(RemoveStmt, RemoveStatement, Remove);
+
+/// ## REMOVE PARAM statement
+/// build by `Stmt::remove().param()`
+///
+/// it can be used alone or in `TransactionStmt`, but not in a block or a subquery,
+/// where surrealdb renders it without `$`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoveParamStmt {
+    origin: RemoveParamStatement,
+}
+
+impl ToString for RemoveParamStmt {
+    fn to_string(&self) -> String {
+        remove_param(&self.origin)
+    }
+}
+
+impl_stmt_bridge!(
+    RemoveParamStmt,
+    RemoveParamStatement,
+    Remove(RemoveStatement::Param)
+);
+
+/// surrealdb renders REMOVE PARAM without `$`, which can not be parsed back
+pub(crate) fn remove_param(param: &RemoveParamStatement) -> String {
+    format!("REMOVE PARAM ${}", param.name.0)
+}
+
+/// only normal table can be removed
+fn table_name(table: SurrealTable) -> Ident {
+    match table {
+        SurrealTable::Table(table) => table.0.into(),
+        _ => panic!("REMOVE statement can only use normal table"),
+    }
+}
+
+#[cfg(test)]
+mod test_remove_stmt {
+    use surrealdb::sql::Base;
+
+    use crate::core::sql::Field;
+
+    use super::Remove;
+
+    #[test]
+    fn base() {
+        let ns = Remove::new().ns("surrealdb");
+        let db = Remove::new().db("blog");
+        let user = Remove::new().user("username", Base::Root);
+        let token = Remove::new().token("token_name", Base::Sc("account".into()));
+        let scope = Remove::new().scope("account");
+        assert_eq!(ns.to_string().as_str(), "REMOVE NAMESPACE surrealdb");
+        assert_eq!(db.to_string().as_str(), "REMOVE DATABASE blog");
+        assert_eq!(user.to_string().as_str(), "REMOVE USER username ON ROOT");
+        assert_eq!(
+            token.to_string().as_str(),
+            "REMOVE TOKEN token_name ON SCOPE account"
+        );
+        assert_eq!(scope.to_string().as_str(), "REMOVE SCOPE account");
+    }
+
+    #[test]
+    fn table() {
+        let table = Remove::new().table("user".into());
+        let field = Remove::new().field(Field::new("address.city"), "user".into());
+        let index = Remove::new().index("userEmailIndex", "user".into());
+        let event = Remove::new().event("email", "user".into());
+        assert_eq!(table.to_string().as_str(), "REMOVE TABLE user");
+        assert_eq!(
+            field.to_string().as_str(),
+            "REMOVE FIELD address.city ON user"
+        );
+        assert_eq!(
+            index.to_string().as_str(),
+            "REMOVE INDEX userEmailIndex ON user"
+        );
+        assert_eq!(event.to_string().as_str(), "REMOVE EVENT email ON user");
+    }
+
+    #[test]
+    fn others() {
+        let function = Remove::new().function("fn::greet");
+        let analyzer = Remove::new().analyzer("ascii");
+        let param = Remove::new().param("$endpointBase");
+        assert_eq!(function.to_string().as_str(), "REMOVE FUNCTION fn::greet");
+        assert_eq!(analyzer.to_string().as_str(), "REMOVE ANALYZER ascii");
+        assert_eq!(param.to_string().as_str(), "REMOVE PARAM $endpointBase");
+    }
+
+    #[test]
+    #[should_panic]
+    fn thing() {
+        let _ = Remove::new().table(("user", "tobie").into());
+    }
+}
@@ -0,0 +1,92 @@
+use surrealdb::sql::{statements::OutputStatement, Fetch, Fetchs, Value};
+
+use super::{sql::Field, StmtBridge, SubqueryStmt};
+use crate::impl_stmt_bridge;
+
+/// ## create RETURN statement
+/// The RETURN statement can be used to return an implicit value or the result of a query,
+/// and to set the return value for a transaction, block, or function.
+/// ```
+/// RETURN @value [ FETCH @fields ... ];
+/// ```
+/// ### example
+/// ```
+/// let person = Param::new("person");
+/// let r#return = ReturnStmt::new(person.field("name"));
+/// assert_eq!(r#return.to_string().as_str(), "RETURN $person.name");
+/// let r#return = ReturnStmt::subquery(Stmt::select().table("person".into()).field_all())
+///     .fetch(vec![Field::new("friends")]);
+/// assert_eq!(
+///     r#return.to_string().as_str(),
+///     "RETURN (SELECT * FROM person) FETCH friends"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReturnStmt {
+    origin: OutputStatement,
+}
+
+impl ReturnStmt {
+    /// ## create RETURN statement
+    /// - value : the value to return
+    pub fn new(value: impl Into<Value>) -> Self {
+        ReturnStmt {
+            origin: OutputStatement {
+                what: value.into(),
+                fetch: None,
+            },
+        }
+    }
+    /// ## RETURN (@statement)
+    /// return the result of a statement, such as SELECT
+    pub fn subquery<S>(stmt: S) -> Self
+    where
+        S: SubqueryStmt,
+    {
+        ReturnStmt::new(stmt.into())
+    }
+    pub fn fetch(mut self, fetchs: Vec<Field>) -> Self {
+        let fetchs = fetchs
+            .into_iter()
+            .map(|x| Fetch(x.to_idiom()))
+            .collect::<Vec<Fetch>>();
+        self.origin.fetch.replace(Fetchs(fetchs));
+        self
+    }
+}
+
+impl ToString for ReturnStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(ReturnStmt, OutputStatement, Output);
+
+#[cfg(test)]
+mod test_return_stmt {
+    use crate::core::{
+        select::SelectStmt,
+        sql::{Field, Param},
+    };
+
+    use super::ReturnStmt;
+
+    #[test]
+    fn simple() {
+        let value = ReturnStmt::new(123.456);
+        let param = ReturnStmt::new(Param::new("person").field("name"));
+        assert_eq!(value.to_string().as_str(), "RETURN 123.456f");
+        assert_eq!(param.to_string().as_str(), "RETURN $person.name");
+    }
+
+    #[test]
+    fn subquery() {
+        let r#return = ReturnStmt::subquery(SelectStmt::new().table("person".into()).field_all())
+            .fetch(vec![Field::new("friends")]);
+        assert_eq!(
+            r#return.to_string().as_str(),
+            "RETURN (SELECT * FROM person) FETCH friends"
+        );
+    }
+}
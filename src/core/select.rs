@@ -212,7 +212,7 @@ impl SelectStmt {
     }
 }
 
-impl_stmt_bridge!(SelectStmt, SelectStatement);
+impl_stmt_bridge!(SelectStmt, SelectStatement, Select);
 
 impl ToString for SelectStmt {
     fn to_string(&self) -> String {
@@ -223,12 +223,46 @@ impl ToString for SelectStmt {
 #[cfg(test)]
 mod test_select_stmt {
 
-    use surrealdb::sql::Duration;
+    use surrealdb::sql::{Dir, Duration, Operator};
 
-    use crate::core::sql::{Field, Order};
+    use crate::core::sql::{Cond, Edges, Field, Order};
 
     use super::SelectStmt;
     #[test]
+    fn graph_cond() {
+        let select = SelectStmt::new().table("person".into()).field_all().cond(
+            Edges::new("".into(), Dir::Out, "likes".into())
+                .cond(
+                    Cond::new()
+                        .left("score")
+                        .op(Operator::MoreThan)
+                        .right(5.into()),
+                )
+                .into(),
+        );
+        assert_eq!(
+            select.to_string().as_str(),
+            "SELECT * FROM person WHERE ->(likes WHERE score > 5)"
+        );
+    }
+    #[test]
+    fn record_edges() {
+        let select = SelectStmt::new()
+            .table(
+                Edges::new(
+                    ("person", "tobie").into(),
+                    Dir::Out,
+                    ("person", "jaime").into(),
+                )
+                .into(),
+            )
+            .field_all();
+        assert_eq!(
+            select.to_string().as_str(),
+            "SELECT * FROM person:tobie->(person WHERE id = person:jaime)"
+        );
+    }
+    #[test]
     fn complex() {
         let select1 = SelectStmt::new()
             .table("person".into())
@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use surrealdb::sql::{statements::ShowStatement, Datetime, Thing};
+
+use super::{sql::SurrealTable, StmtBridge};
+use crate::impl_stmt_bridge;
+
+/// ## create SHOW CHANGES statement
+/// The SHOW CHANGES statement is used to read the change feed of a table or a database,
+/// which needs CHANGEFEED to be defined on the table or the database.
+/// Decode the result by `Vec<ChangeSet>`
+/// ```
+/// SHOW CHANGES FOR TABLE @tableName SINCE @versionstamp|@timestamp [ LIMIT @number ];
+/// ```
+/// ### example
+/// ```
+/// let show = Stmt::show_changes("reading".into()).since_versionstamp(1).limit(10);
+/// assert_eq!(
+///     show.to_string().as_str(),
+///     "SHOW CHANGES FOR TABLE reading SINCE 1 LIMIT 10"
+/// );
+/// let mut response = DB.query(show.to_string()).await?;
+/// let changes: Vec<ChangeSet> = response.take(0)?;
+/// // read the next page
+/// let show = show.next_page(&changes);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowChangesStmt {
+    origin: ShowStatement,
+}
+
+impl ShowChangesStmt {
+    /// ## create SHOW CHANGES statement
+    /// default `SHOW CHANGES FOR DATABASE SINCE 0`
+    pub fn new() -> Self {
+        // `ShowSince` is not exported by surrealdb, so the statement is built from its serialized form
+        let origin = serde_json::from_value(json!({
+            "table": null,
+            "since": { "Versionstamp": 0 },
+            "limit": null
+        }))
+        .expect("ShowStatement can not be built");
+        ShowChangesStmt { origin }
+    }
+    /// ## SHOW CHANGES FOR TABLE @table
+    pub fn table(mut self, table: SurrealTable) -> Self {
+        self.origin.table.replace(table.into());
+        self
+    }
+    /// ## SHOW CHANGES FOR DATABASE
+    pub fn db(mut self) -> Self {
+        self.origin.table = None;
+        self
+    }
+    /// ## SINCE @versionstamp
+    /// changes whose versionstamp is greater than or equal to it are returned
+    pub fn since_versionstamp(self, versionstamp: u64) -> Self {
+        self.since(json!({ "Versionstamp": versionstamp }))
+    }
+    /// ## SINCE @timestamp
+    pub fn since_timestamp(self, timestamp: impl Into<Datetime>) -> Self {
+        self.since(json!({ "Timestamp": timestamp.into() }))
+    }
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.origin.limit.replace(limit);
+        self
+    }
+    /// ## read the changes after the last change set
+    /// do nothing if `changes` is empty
+    pub fn next_page<T>(self, changes: &[ChangeSet<T>]) -> Self {
+        match changes.last() {
+            Some(last) => self.since_versionstamp(last.versionstamp + 1),
+            None => self,
+        }
+    }
+    fn since(mut self, since: serde_json::Value) -> Self {
+        let mut origin = serde_json::to_value(&self.origin).unwrap();
+        origin["since"] = since;
+        self.origin = serde_json::from_value(origin).expect("invalid SINCE of SHOW CHANGES");
+        self
+    }
+}
+
+impl ToString for ShowChangesStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(ShowChangesStmt, ShowStatement, Show);
+
+/// ## result of SHOW CHANGES
+/// all changes committed at the same versionstamp,
+/// persist `versionstamp` to continue reading from it
+/// - T : type of the updated records
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeSet<T = serde_json::Value> {
+    pub versionstamp: u64,
+    pub changes: Vec<Change<T>>,
+}
+
+/// ## a single change in `ChangeSet`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Change<T = serde_json::Value> {
+    /// the record after it is created or updated
+    Update(T),
+    /// id of the deleted record
+    Delete { id: Thing },
+    /// the table is defined
+    DefineTable { name: String },
+}
+
+#[cfg(test)]
+mod test_show_changes_stmt {
+    use serde::Deserialize;
+    use serde_json::json;
+    use surrealdb::sql::{Datetime, Thing};
+
+    use super::{Change, ChangeSet, ShowChangesStmt};
+
+    #[test]
+    fn simple() {
+        let table = ShowChangesStmt::new()
+            .table("reading".into())
+            .since_versionstamp(1)
+            .limit(10);
+        let db = ShowChangesStmt::new().db();
+        assert_eq!(
+            table.to_string().as_str(),
+            "SHOW CHANGES FOR TABLE reading SINCE 1 LIMIT 10"
+        );
+        assert_eq!(db.to_string().as_str(), "SHOW CHANGES FOR DATABASE SINCE 0");
+    }
+
+    #[test]
+    fn timestamp() {
+        let datetime = Datetime::try_from("2023-09-07T01:23:52Z").unwrap();
+        let show = ShowChangesStmt::new()
+            .table("reading".into())
+            .since_timestamp(datetime);
+        assert_eq!(
+            show.to_string().as_str(),
+            "SHOW CHANGES FOR TABLE reading SINCE '2023-09-07T01:23:52Z'"
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    struct Reading {
+        id: Thing,
+        story: String,
+    }
+
+    #[test]
+    fn change_set() {
+        let changes: Vec<ChangeSet<Reading>> = serde_json::from_value(json!([
+            {
+                "versionstamp": 65536,
+                "changes": [
+                    { "define_table": { "name": "reading" } }
+                ]
+            },
+            {
+                "versionstamp": 131072,
+                "changes": [
+                    {
+                        "update": {
+                            "id": { "tb": "reading", "id": { "String": "story" } },
+                            "story": "Once upon a time"
+                        }
+                    },
+                    { "delete": { "id": { "tb": "reading", "id": { "String": "story" } } } }
+                ]
+            }
+        ]))
+        .unwrap();
+        assert_eq!(
+            changes[0].changes[0],
+            Change::DefineTable {
+                name: "reading".to_string()
+            }
+        );
+        match &changes[1].changes[0] {
+            Change::Update(reading) => assert_eq!(reading.story.as_str(), "Once upon a time"),
+            _ => panic!("expect update"),
+        }
+        assert_eq!(
+            changes[1].changes[1],
+            Change::Delete {
+                id: ("reading", "story").into()
+            }
+        );
+        let next = ShowChangesStmt::new()
+            .table("reading".into())
+            .limit(10)
+            .next_page(&changes);
+        assert_eq!(
+            next.to_string().as_str(),
+            "SHOW CHANGES FOR TABLE reading SINCE 131073 LIMIT 10"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use serde_json::json;
+use surrealdb::sql::{statements::SleepStatement, Duration};
+
+use super::StmtBridge;
+use crate::impl_stmt_bridge;
+
+/// ## create SLEEP statement
+/// The SLEEP statement is used to introduce a delay or pause in the execution of a query or a batch of queries for a specific amount of time.
+/// ```
+/// SLEEP @duration;
+/// ```
+/// ### example
+/// ```
+/// let sleep = SleepStmt::new(Duration::from_millis(500));
+/// assert_eq!(sleep.to_string().as_str(), "SLEEP 500ms");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SleepStmt {
+    origin: SleepStatement,
+}
+
+impl SleepStmt {
+    /// ## create SLEEP statement
+    /// - duration : how long to sleep
+    pub fn new(duration: Duration) -> Self {
+        // `SleepStatement::duration` is not public, build it from the serialized form
+        let origin = serde_json::from_value(json!({ "duration": duration }))
+            .expect("SleepStatement can not be built from Duration");
+        SleepStmt { origin }
+    }
+}
+
+impl ToString for SleepStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(SleepStmt, SleepStatement, Sleep);
+
+#[cfg(test)]
+mod test_sleep_stmt {
+    use surrealdb::sql::Duration;
+
+    use super::SleepStmt;
+
+    #[test]
+    fn simple() {
+        let sleep = SleepStmt::new(Duration::from_millis(500));
+        let secs = SleepStmt::new(Duration::from_secs(10));
+        assert_eq!(sleep.to_string().as_str(), "SLEEP 500ms");
+        assert_eq!(secs.to_string().as_str(), "SLEEP 10s");
+    }
+}
@@ -0,0 +1,88 @@
+use serde_json::{json, Value};
+use surrealdb::sql;
+
+/// ## tokenizer of DEFINE ANALYZER
+/// - BLANK : split by whitespace
+/// - CAMEL : split by camel case
+/// - CLASS : split by unicode class (digit, letter, punctuation, blank)
+/// - PUNCT : split by punctuation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tokenizer {
+    Blank,
+    Camel,
+    Class,
+    Punct,
+}
+
+impl From<Tokenizer> for sql::Tokenizer {
+    fn from(value: Tokenizer) -> Self {
+        match value {
+            Tokenizer::Blank => sql::Tokenizer::Blank,
+            Tokenizer::Camel => sql::Tokenizer::Camel,
+            Tokenizer::Class => sql::Tokenizer::Class,
+            Tokenizer::Punct => sql::Tokenizer::Punct,
+        }
+    }
+}
+
+/// ## filter of DEFINE ANALYZER
+/// - ASCII
+/// - LOWERCASE
+/// - UPPERCASE
+/// - EDGENGRAM(@min,@max)
+/// - NGRAM(@min,@max)
+/// - SNOWBALL(@language)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    Ascii,
+    Lowercase,
+    Uppercase,
+    EdgeNgram(u16, u16),
+    Ngram(u16, u16),
+    Snowball(Language),
+}
+
+/// ## language of SNOWBALL filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Arabic,
+    Danish,
+    Dutch,
+    English,
+    French,
+    German,
+    Greek,
+    Hungarian,
+    Italian,
+    Norwegian,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+    Swedish,
+    Tamil,
+    Turkish,
+}
+
+/// `surrealdb::sql::filter::Filter` is not exported,
+/// so convert to the serialized form of it
+impl From<Filter> for Value {
+    fn from(value: Filter) -> Self {
+        match value {
+            Filter::Ascii => json!("Ascii"),
+            Filter::Lowercase => json!("Lowercase"),
+            Filter::Uppercase => json!("Uppercase"),
+            Filter::EdgeNgram(min, max) => json!({ "EdgeNgram": [min, max] }),
+            Filter::Ngram(min, max) => json!({ "Ngram": [min, max] }),
+            Filter::Snowball(language) => json!({ "Snowball": Value::from(language) }),
+        }
+    }
+}
+
+/// `surrealdb::sql::language::Language` is not exported,
+/// so convert to the serialized form of it
+impl From<Language> for Value {
+    fn from(value: Language) -> Self {
+        json!(format!("{:?}", value))
+    }
+}
@@ -1,8 +1,7 @@
-use super::Field;
-use std::mem;
-use surrealdb::sql::{self, Expression, Operator, Value};
-
-// use super::Edges;(not reachable)
+use super::{Edges, Field};
+use serde::Serialize;
+use std::{mem, ops::Not};
+use surrealdb::sql::{self, to_value, Expression, Operator, Subquery, Value};
 
 /// # conditional expression（where）
 /// Use in the WHERE clause to construct conditional expressions
@@ -26,11 +25,11 @@ use surrealdb::sql::{self, Expression, Operator, Value};
 /// .left_value(Value::Array(vec![
 ///     "Jack","John"
 /// ].into()))
-/// .op(surrealdb::sql::Operator::Contain)
-/// .right(Value::Strand( "(SELECT name FROM vip WHERE id = '1')".into()));
+/// .op(surrealdb::sql::Operator::ContainAny)
+/// .right(Stmt::select().table("vip".into()).fields(vec![Field::new("name")]).into());
 /// assert_eq!(
 /// cond.to_string().as_str(),
-/// "WHERE ['Jack', 'John'] CONTAINS \"(SELECT name FROM vip WHERE id = '1')\""
+/// "WHERE ['Jack', 'John'] CONTAINSANY (SELECT name FROM vip)"
 /// );
 /// //----------------------------------------------------------------
 /// let cond = Cond::new()
@@ -38,6 +37,13 @@ use surrealdb::sql::{self, Expression, Operator, Value};
 /// .op(surrealdb::sql::Operator::Equal)
 /// .right("Matt".into());
 /// assert_eq!(cond.to_string().as_str(), "WHERE username = 'Matt'");
+/// //----------------------------------------------------------------
+/// let cond = Cond::new()
+/// .left("age")
+/// .op(surrealdb::sql::Operator::MoreThan)
+/// .right(18.into())
+/// .and(!Cond::new().left("name").op(surrealdb::sql::Operator::Equal).right("Matt".into()));
+/// assert_eq!(cond.to_string().as_str(), "WHERE age > 18 AND !(name = 'Matt')");
 /// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct Cond(sql::Cond);
@@ -52,6 +58,25 @@ impl Cond {
             r: Value::default(),
         }))))
     }
+    /// ## build a unary cond instance
+    /// use `Expression::Unary`, such as: `!active` , `-balance`
+    ///
+    /// the operand is grouped if it is a binary expression
+    /// ### example
+    /// ```
+    /// let cond = Cond::unary(Operator::Not, Field::new("active").into());
+    /// assert_eq!(cond.to_string().as_str(), "WHERE !active");
+    /// ```
+    pub fn unary(op: Operator, value: Value) -> Cond {
+        Cond(sql::Cond(Value::Expression(Box::new(Expression::Unary {
+            o: op,
+            v: group_binary(value),
+        }))))
+    }
+    /// is the cond a unary expression
+    pub fn is_unary(&self) -> bool {
+        matches!(&self.0 .0, Value::Expression(expression) if matches!(expression.as_ref(), Expression::Unary { .. }))
+    }
     pub fn to_origin(self) -> sql::Cond {
         self.0
     }
@@ -79,18 +104,18 @@ impl Cond {
     /// let cond = Cond::new()
     ///     .left_value(Value::Array(vec!["Jack", "John"].into()))
     ///     .op(surrealdb::sql::Operator::Contain)
-    ///     .right(Value::Strand(
-    ///         "(SELECT name FROM vip WHERE id = '1')".into(),
-    ///     ));
+    ///     .right(Stmt::select().table("vip".into()).fields(vec![Field::new("name")]).into());
     /// assert_eq!(
     ///     cond.to_string().as_str(),
-    ///     "WHERE ['Jack', 'John'] CONTAINS \"(SELECT name FROM vip WHERE id = '1')\""
+    ///     "WHERE ['Jack', 'John'] CONTAINS (SELECT name FROM vip)"
     /// );
     /// ```
+    ///
+    /// for unary expression, the operand is replaced
     pub fn left_value(mut self, left: Value) -> Self {
         self.replace(|expression| match expression {
-            Expression::Unary { o: _, v: _ } => {
-                panic!("Unexpected unary expression , If you see this panic , please send issue!")
+            Expression::Unary { o: _, v } => {
+                let _ = mem::replace(v, group_binary(left));
             }
             Expression::Binary { l, o: _, r: _ } => {
                 let _ = mem::replace(l, left.into());
@@ -115,10 +140,11 @@ impl Cond {
         self.left(left)
     }
     /// ## build right
+    /// for unary expression, the operand is replaced
     pub fn right(mut self, right: Value) -> Self {
         self.replace(|expression| match expression {
-            Expression::Unary { o: _, v: _ } => {
-                panic!("Unexpected unary expression , If you see this panic , please send issue!")
+            Expression::Unary { o: _, v } => {
+                let _ = mem::replace(v, group_binary(right));
             }
             Expression::Binary { l: _, o: _, r } => {
                 let _ = mem::replace(r, right);
@@ -129,8 +155,8 @@ impl Cond {
     /// ## Building logical operators
     pub fn op(mut self, op: Operator) -> Self {
         self.replace(|expression| match expression {
-            Expression::Unary { o: _, v: _ } => {
-                panic!("Unexpected unary expression , If you see this panic , please send issue!")
+            Expression::Unary { o, v: _ } => {
+                let _ = mem::replace(o, op);
             }
             Expression::Binary { l: _, o, r: _ } => {
                 let _ = mem::replace(o, op);
@@ -139,6 +165,175 @@ impl Cond {
         self
     }
 
+    /// ## build cond from field
+    /// use with the set and membership helpers, such as: `contains_any()` , `inside()`
+    /// ### example
+    /// ```
+    /// let cond = Cond::field("tags").contains_any(vec!["rust", "surrealdb"]);
+    /// assert_eq!(
+    ///     cond.to_string().as_str(),
+    ///     "WHERE tags CONTAINSANY ['rust', 'surrealdb']"
+    /// );
+    /// ```
+    pub fn field(field: impl Into<Field>) -> Self {
+        Cond::new().left(field)
+    }
+    /// ## CONTAINS
+    /// the left side contains the value
+    pub fn contains<T: Serialize>(self, value: T) -> Self {
+        self.op_value(Operator::Contain, value)
+    }
+    /// ## CONTAINSNOT
+    pub fn contains_not<T: Serialize>(self, value: T) -> Self {
+        self.op_value(Operator::NotContain, value)
+    }
+    /// ## CONTAINSALL
+    /// the left side contains all values of the collection
+    pub fn contains_all<T: Serialize>(self, values: T) -> Self {
+        self.op_value(Operator::ContainAll, values)
+    }
+    /// ## CONTAINSANY
+    /// the left side contains any value of the collection
+    pub fn contains_any<T: Serialize>(self, values: T) -> Self {
+        self.op_value(Operator::ContainAny, values)
+    }
+    /// ## CONTAINSNONE
+    /// the left side contains no value of the collection
+    pub fn contains_none<T: Serialize>(self, values: T) -> Self {
+        self.op_value(Operator::ContainNone, values)
+    }
+    /// ## INSIDE (IN)
+    /// the left side is inside the collection
+    pub fn inside<T: Serialize>(self, values: T) -> Self {
+        self.op_value(Operator::Inside, values)
+    }
+    /// ## NOTINSIDE (NOT IN)
+    pub fn not_inside<T: Serialize>(self, values: T) -> Self {
+        self.op_value(Operator::NotInside, values)
+    }
+    /// ## ALLINSIDE
+    /// all values of the left side are inside the collection
+    pub fn all_inside<T: Serialize>(self, values: T) -> Self {
+        self.op_value(Operator::AllInside, values)
+    }
+    /// ## ANYINSIDE
+    /// any value of the left side is inside the collection
+    pub fn any_inside<T: Serialize>(self, values: T) -> Self {
+        self.op_value(Operator::AnyInside, values)
+    }
+    /// ## NONEINSIDE
+    /// no value of the left side is inside the collection
+    pub fn none_inside<T: Serialize>(self, values: T) -> Self {
+        self.op_value(Operator::NoneInside, values)
+    }
+    /// ## fuzzy match `~`
+    pub fn fuzzy(self, value: &str) -> Self {
+        self.op(Operator::Like).right(value.into())
+    }
+    /// ## full-text match `@@` or `@ref@`
+    /// - reference : match reference used by `search::score()` and `search::highlight()`, None for `@@`
+    /// - query : the full-text query
+    ///
+    /// the field needs a SEARCH index
+    /// ### example
+    /// ```
+    /// let cond = Cond::field("title").matches(Some(1), "hello world");
+    /// assert_eq!(cond.to_string().as_str(), "WHERE title @1@ 'hello world'");
+    /// let cond = Cond::field("title").matches(None, "hello world");
+    /// assert_eq!(cond.to_string().as_str(), "WHERE title @@ 'hello world'");
+    /// ```
+    pub fn matches(self, reference: Option<u8>, query: &str) -> Self {
+        self.op(Operator::Matches(reference)).right(query.into())
+    }
+    /// set operator and the serialized value as right
+    fn op_value<T: Serialize>(self, op: Operator, value: T) -> Self {
+        match to_value(value) {
+            Ok(value) => self.op(op).right(value),
+            Err(e) => panic!("{}", e),
+        }
+    }
+    /// ## NOT
+    /// negate the whole condition, such as: `!(age > 18)`
+    /// ### example
+    /// ```
+    /// let cond = Cond::new().left("age").op(Operator::MoreThan).right(18.into()).not();
+    /// assert_eq!(cond.to_string().as_str(), "WHERE !(age > 18)");
+    /// ```
+    ///
+    /// `std::ops::Not` is also implemented, this one can be called without importing the trait
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Cond(sql::Cond(Value::Expression(Box::new(Expression::Unary {
+            o: Operator::Not,
+            v: group_binary(self.to_value()),
+        }))))
+    }
+    /// ## AND
+    /// both conditions must be true,
+    /// an OR condition on either side is grouped
+    /// ### example
+    /// ```
+    /// let cond = Cond::new()
+    ///     .left("age")
+    ///     .op(Operator::MoreThanOrEqual)
+    ///     .right(18.into())
+    ///     .and(Cond::new().left("verified").op(Operator::Equal).right(true.into()));
+    /// assert_eq!(cond.to_string().as_str(), "WHERE age >= 18 AND verified = true");
+    /// ```
+    pub fn and(self, other: Cond) -> Self {
+        self.logic(Operator::And, other)
+    }
+    /// ## OR
+    /// either condition must be true,
+    /// an AND condition on either side is grouped
+    /// ### example
+    /// ```
+    /// let cond = Cond::new()
+    ///     .left("role")
+    ///     .op(Operator::Equal)
+    ///     .right("admin".into())
+    ///     .or(Cond::new().left("role").op(Operator::Equal).right("owner".into()));
+    /// assert_eq!(cond.to_string().as_str(), "WHERE role = 'admin' OR role = 'owner'");
+    /// ```
+    pub fn or(self, other: Cond) -> Self {
+        self.logic(Operator::Or, other)
+    }
+    /// ## group the condition with parentheses
+    /// such as: `(age > 18 OR verified = true)`
+    pub fn group(self) -> Self {
+        match self.0 .0 {
+            Value::Subquery(_) => self,
+            value => Cond(sql::Cond(Value::Subquery(Box::new(Subquery::Value(value))))),
+        }
+    }
+    /// value of the condition without `WHERE`
+    pub fn to_value(self) -> Value {
+        self.0 .0
+    }
+    /// join two conditions by AND | OR
+    fn logic(self, op: Operator, other: Cond) -> Self {
+        let l = self.group_logic(&op).to_value();
+        let r = other.group_logic(&op).to_value();
+        Cond(sql::Cond(Value::Expression(Box::new(Expression::Binary {
+            l,
+            o: op,
+            r,
+        }))))
+    }
+    /// group the condition if it is joined by another logical operator
+    fn group_logic(self, op: &Operator) -> Self {
+        match &self.0 .0 {
+            Value::Expression(expression) => match expression.as_ref() {
+                Expression::Binary {
+                    o: o @ (Operator::And | Operator::Or),
+                    ..
+                } if o != op => self.group(),
+                _ => self,
+            },
+            _ => self,
+        }
+    }
+
     /// Replace fields in expressions
     ///
     /// maybe:
@@ -161,21 +356,224 @@ impl Cond {
     }
 }
 
+/// group the value if it is a binary expression,
+/// otherwise the operator of unary expression only applies to the left side
+fn group_binary(value: Value) -> Value {
+    match &value {
+        Value::Expression(expression)
+            if matches!(expression.as_ref(), Expression::Binary { .. }) =>
+        {
+            Value::Subquery(Box::new(Subquery::Value(value)))
+        }
+        _ => value,
+    }
+}
+
+/// ## NOT
+/// same as `Cond::not`, such as: `!cond`
+impl Not for Cond {
+    type Output = Cond;
+
+    fn not(self) -> Self::Output {
+        Cond::not(self)
+    }
+}
+
 impl ToString for Cond {
     fn to_string(&self) -> String {
         self.0.to_string()
     }
 }
 
-// impl From<Edges> for Cond {
-//     fn from(value: Edges) -> Self {
-//         Cond(sql::Cond(value.into()))
-//     }
-// }
+/// ## graph path as condition
+/// the record matches if the path is not empty
+/// ### example
+/// ```
+/// let cond: Cond = Edges::new("".into(), Dir::Out, "knows".into()).into();
+/// assert_eq!(cond.to_string().as_str(), "WHERE ->knows");
+/// ```
+impl From<Edges> for Cond {
+    fn from(value: Edges) -> Self {
+        Cond(value.into())
+    }
+}
+
+/// ## value as condition
+/// the record matches if the value is truthy
+/// ### example
+/// ```
+/// let cond: Cond = Value::from(Field::new("active")).into();
+/// assert_eq!(cond.to_string().as_str(), "WHERE active");
+/// ```
+impl From<Value> for Cond {
+    fn from(value: Value) -> Self {
+        Cond(sql::Cond(value))
+    }
+}
+
+/// ## build `Cond` in Rust syntax
+/// - `&&` , `||` : AND , OR (AND is evaluated first)
+/// - `!` : NOT, such as: `!(age > 18)` , `!active`
+/// - `( ... )` : group
+/// - a field or a param alone is truthy check, such as: `active && age > 16`
+/// - `==` , `!=` , `>` , `>=` , `<` , `<=` , `~` , `!~`
+/// - `CONTAINS` , `CONTAINSNOT` , `CONTAINSALL` , `CONTAINSANY` , `CONTAINSNONE`
+/// - `IN` , `INSIDE` , `NOTINSIDE` , `ALLINSIDE` , `ANYINSIDE` , `NONEINSIDE`
+///
+/// The left side is a field path (`address.city`) or a param (`$user.name`),
+/// the right side is a param or a Rust expression which can be converted into `Value`,
+/// wrap the expression in parentheses if it starts with an operator, such as: `(-1)` , `(&name)`
+///
+/// Malformed conditions are rejected at compile time
+/// ### example
+/// ```
+/// let cond = surreal_use::cond!(age > 16 && name == $name || tags CONTAINS "x");
+/// assert_eq!(
+///     cond.to_string().as_str(),
+///     "WHERE (age > 16 AND name = $name) OR tags CONTAINS 'x'"
+/// );
+/// ```
+/// `surrealdb` is not required in the dependencies of the caller
+/// ```
+/// let cond = surreal_use::cond!(age > 16 && active);
+/// assert_eq!(cond.to_string().as_str(), "WHERE age > 16 AND active");
+/// ```
+/// the left side can not be a literal
+/// ```compile_fail
+/// let _ = surreal_use::cond!(16 < age);
+/// ```
+/// the operator can not be omitted
+/// ```compile_fail
+/// let _ = surreal_use::cond!(age 16);
+/// ```
+#[macro_export]
+macro_rules! cond {
+    // split by `||`
+    (@or [$($acc:tt)*] [$($cur:tt)+] || $($rest:tt)+) => {
+        $crate::cond!(@or [$($acc)* ($crate::cond!(@and [] [] $($cur)+))] [] $($rest)+)
+    };
+    (@or [$($acc:tt)*] [$($cur:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::cond!(@or [$($acc)*] [$($cur)* $t] $($rest)*)
+    };
+    (@or [$($acc:tt)*] [$($cur:tt)+]) => {
+        $crate::cond!(@fold or $($acc)* ($crate::cond!(@and [] [] $($cur)+)))
+    };
+    // split by `&&`
+    (@and [$($acc:tt)*] [$($cur:tt)+] && $($rest:tt)+) => {
+        $crate::cond!(@and [$($acc)* ($crate::cond!(@unit $($cur)+))] [] $($rest)+)
+    };
+    (@and [$($acc:tt)*] [$($cur:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::cond!(@and [$($acc)*] [$($cur)* $t] $($rest)*)
+    };
+    (@and [$($acc:tt)*] [$($cur:tt)+]) => {
+        $crate::cond!(@fold and $($acc)* ($crate::cond!(@unit $($cur)+)))
+    };
+    (@fold $logic:ident $first:tt) => {
+        $first
+    };
+    (@fold $logic:ident $first:tt $second:tt $($rest:tt)*) => {
+        $crate::cond!(@fold $logic ($first.$logic($second)) $($rest)*)
+    };
+    // NOT and group
+    (@unit ! ($($t:tt)+)) => {
+        !$crate::cond!(@or [] [] $($t)+)
+    };
+    (@unit ! $($t:tt)+) => {
+        $crate::core::sql::Cond::unary(
+            $crate::__surrealdb::sql::Operator::Not,
+            $crate::cond!(@left $($t)+),
+        )
+    };
+    (@unit ($($t:tt)+)) => {
+        $crate::cond!(@or [] [] $($t)+).group()
+    };
+    // field or param alone
+    (@unit $field:ident $(. $path:ident)*) => {
+        $crate::core::sql::Cond::from($crate::cond!(@left $field $(. $path)*))
+    };
+    (@unit $d:tt $name:ident $(. $path:ident)*) => {
+        $crate::core::sql::Cond::from($crate::cond!(@param $d $name $(. $path)*))
+    };
+    (@unit $($t:tt)+) => {
+        $crate::cond!(@cmp [] $($t)+)
+    };
+    // find the operator
+    (@cmp [$($l:tt)+] == $($r:tt)+) => { $crate::cond!(@build [$($l)+] Equal $($r)+) };
+    (@cmp [$($l:tt)+] != $($r:tt)+) => { $crate::cond!(@build [$($l)+] NotEqual $($r)+) };
+    (@cmp [$($l:tt)+] > $($r:tt)+) => { $crate::cond!(@build [$($l)+] MoreThan $($r)+) };
+    (@cmp [$($l:tt)+] >= $($r:tt)+) => { $crate::cond!(@build [$($l)+] MoreThanOrEqual $($r)+) };
+    (@cmp [$($l:tt)+] < $($r:tt)+) => { $crate::cond!(@build [$($l)+] LessThan $($r)+) };
+    (@cmp [$($l:tt)+] <= $($r:tt)+) => { $crate::cond!(@build [$($l)+] LessThanOrEqual $($r)+) };
+    (@cmp [$($l:tt)+] ~ $($r:tt)+) => { $crate::cond!(@build [$($l)+] Like $($r)+) };
+    (@cmp [$($l:tt)+] !~ $($r:tt)+) => { $crate::cond!(@build [$($l)+] NotLike $($r)+) };
+    (@cmp [$($l:tt)+] CONTAINS $($r:tt)+) => { $crate::cond!(@build [$($l)+] Contain $($r)+) };
+    (@cmp [$($l:tt)+] CONTAINSNOT $($r:tt)+) => { $crate::cond!(@build [$($l)+] NotContain $($r)+) };
+    (@cmp [$($l:tt)+] CONTAINSALL $($r:tt)+) => { $crate::cond!(@build [$($l)+] ContainAll $($r)+) };
+    (@cmp [$($l:tt)+] CONTAINSANY $($r:tt)+) => { $crate::cond!(@build [$($l)+] ContainAny $($r)+) };
+    (@cmp [$($l:tt)+] CONTAINSNONE $($r:tt)+) => { $crate::cond!(@build [$($l)+] ContainNone $($r)+) };
+    (@cmp [$($l:tt)+] IN $($r:tt)+) => { $crate::cond!(@build [$($l)+] Inside $($r)+) };
+    (@cmp [$($l:tt)+] INSIDE $($r:tt)+) => { $crate::cond!(@build [$($l)+] Inside $($r)+) };
+    (@cmp [$($l:tt)+] NOTINSIDE $($r:tt)+) => { $crate::cond!(@build [$($l)+] NotInside $($r)+) };
+    (@cmp [$($l:tt)+] ALLINSIDE $($r:tt)+) => { $crate::cond!(@build [$($l)+] AllInside $($r)+) };
+    (@cmp [$($l:tt)+] ANYINSIDE $($r:tt)+) => { $crate::cond!(@build [$($l)+] AnyInside $($r)+) };
+    (@cmp [$($l:tt)+] NONEINSIDE $($r:tt)+) => { $crate::cond!(@build [$($l)+] NoneInside $($r)+) };
+    (@cmp [$($l:tt)*] $t:tt $($rest:tt)+) => {
+        $crate::cond!(@cmp [$($l)* $t] $($rest)+)
+    };
+    (@build [$($l:tt)+] $op:ident $($r:tt)+) => {
+        $crate::core::sql::Cond::new()
+            .left_value($crate::cond!(@left $($l)+))
+            .op($crate::__surrealdb::sql::Operator::$op)
+            .right($crate::cond!(@right $($r)+))
+    };
+    // operands
+    (@left $field:ident $(. $path:ident)*) => {
+        $crate::__surrealdb::sql::Value::from($crate::core::sql::Field::from(
+            concat!(stringify!($field) $(, ".", stringify!($path))*),
+        ))
+    };
+    (@left $($t:tt)+) => {
+        $crate::cond!(@param $($t)+)
+    };
+    (@right $d:tt $name:ident $(. $path:ident)*) => {
+        $crate::cond!(@param $d $name $(. $path)*)
+    };
+    (@right $value:expr) => {
+        $crate::__surrealdb::sql::Value::from($value)
+    };
+    (@param $d:tt $name:ident) => {{
+        const _: () = assert!(
+            matches!(stringify!($d).as_bytes(), [b'$']),
+            "expected `$param`"
+        );
+        $crate::__surrealdb::sql::Value::from($crate::core::sql::Param::new(stringify!($name)))
+    }};
+    (@param $d:tt $name:ident . $first:ident $(. $path:ident)*) => {{
+        const _: () = assert!(
+            matches!(stringify!($d).as_bytes(), [b'$']),
+            "expected `$param`"
+        );
+        $crate::__surrealdb::sql::Value::from(
+            $crate::core::sql::Param::new(stringify!($name))
+                .field(concat!(stringify!($first) $(, ".", stringify!($path))*)),
+        )
+    }};
+    (@$rule:ident $($t:tt)*) => {
+        compile_error!(concat!("malformed condition: ", stringify!($($t)*)))
+    };
+    ($($t:tt)+) => {
+        $crate::cond!(@or [] [] $($t)+)
+    };
+}
 
 #[cfg(test)]
 mod test_cond {
-    use surrealdb::sql::{Expression, Value};
+    use surrealdb::sql::{Dir, Expression, Value};
+
+    use crate::core::{
+        select::SelectStmt,
+        sql::{Edges, Field},
+    };
 
     use super::Cond;
 
@@ -192,12 +590,21 @@ mod test_cond {
         let cond = Cond::new()
             .left_value(Value::Array(vec!["Jack", "John"].into()))
             .op(surrealdb::sql::Operator::Contain)
-            .right(Value::Strand(
-                "(SELECT name FROM vip WHERE id = '1')".into(),
-            ));
+            .right(
+                SelectStmt::new()
+                    .table("vip".into())
+                    .fields(vec![Field::new("name")])
+                    .cond(
+                        Cond::new()
+                            .left("id")
+                            .op(surrealdb::sql::Operator::Equal)
+                            .right("1".into()),
+                    )
+                    .into(),
+            );
         assert_eq!(
             cond.to_string().as_str(),
-            "WHERE ['Jack', 'John'] CONTAINS \"(SELECT name FROM vip WHERE id = '1')\""
+            "WHERE ['Jack', 'John'] CONTAINS (SELECT name FROM vip WHERE id = '1')"
         );
     }
     /// 简单的例子
@@ -217,4 +624,202 @@ mod test_cond {
         };
         assert_eq!(express.to_string().as_str(), "+'name'");
     }
+    fn eq(field: &str, value: Value) -> Cond {
+        Cond::new()
+            .left(field)
+            .op(surrealdb::sql::Operator::Equal)
+            .right(value)
+    }
+    #[test]
+    fn and_or() {
+        let and = eq("name", "Tobie".into()).and(eq("age", 18.into()));
+        let or = eq("role", "admin".into()).or(eq("role", "owner".into()));
+        assert_eq!(
+            and.to_string().as_str(),
+            "WHERE name = 'Tobie' AND age = 18"
+        );
+        assert_eq!(
+            or.to_string().as_str(),
+            "WHERE role = 'admin' OR role = 'owner'"
+        );
+    }
+    #[test]
+    fn nested() {
+        let cond = eq("verified", true.into())
+            .and(eq("role", "admin".into()).or(eq("role", "owner".into())))
+            .or(eq("name", "root".into()))
+            .and(eq("active", true.into()).and(eq("deleted", false.into())));
+        assert_eq!(
+            cond.to_string().as_str(),
+            "WHERE ((verified = true AND (role = 'admin' OR role = 'owner')) OR name = 'root') AND active = true AND deleted = false"
+        );
+    }
+    #[test]
+    fn not_group() {
+        let not = !eq("role", "admin".into());
+        let method = Cond::not(eq("role", "admin".into()));
+        let group = eq("role", "admin".into())
+            .or(eq("role", "owner".into()))
+            .group();
+        assert_eq!(not.to_string().as_str(), "WHERE !(role = 'admin')");
+        assert_eq!(method, not);
+        assert_eq!(
+            group.to_string().as_str(),
+            "WHERE (role = 'admin' OR role = 'owner')"
+        );
+        assert_eq!(group.clone().group(), group);
+    }
+    #[test]
+    fn unary() {
+        let not = Cond::unary(surrealdb::sql::Operator::Not, Field::new("active").into());
+        let neg = Cond::unary(surrealdb::sql::Operator::Neg, Value::None)
+            .right(Field::new("balance").into())
+            .op(surrealdb::sql::Operator::Not);
+        let binary = Cond::unary(
+            surrealdb::sql::Operator::Not,
+            eq("role", "admin".into()).to_value(),
+        );
+        assert!(not.is_unary());
+        assert_eq!(not.to_string().as_str(), "WHERE !active");
+        assert_eq!(neg.to_string().as_str(), "WHERE !balance");
+        assert_eq!(binary.to_string().as_str(), "WHERE !(role = 'admin')");
+        assert_eq!(binary, !eq("role", "admin".into()));
+    }
+    #[test]
+    fn unary_compose() {
+        let cond = Cond::unary(surrealdb::sql::Operator::Not, Field::new("deleted").into())
+            .and(eq("role", "admin".into()));
+        let neg = Cond::new()
+            .left_value(
+                Cond::unary(surrealdb::sql::Operator::Neg, Field::new("balance").into()).to_value(),
+            )
+            .op(surrealdb::sql::Operator::MoreThan)
+            .right(100.into());
+        assert_eq!(
+            cond.to_string().as_str(),
+            "WHERE !deleted AND role = 'admin'"
+        );
+        assert_eq!(neg.to_string().as_str(), "WHERE -balance > 100");
+    }
+    #[test]
+    fn edges() {
+        let knows = Edges::new("".into(), Dir::Out, "knows".into());
+        let path: Cond = Edges::new(knows.clone().into(), Dir::Out, "person".into())
+            .cond(
+                Cond::new()
+                    .left("influencer")
+                    .op(surrealdb::sql::Operator::Equal)
+                    .right(false.into()),
+            )
+            .into();
+        let count = Cond::new()
+            .left(Edges::new(knows.into(), Dir::Out, "person".into()))
+            .op(surrealdb::sql::Operator::Contain)
+            .right(surrealdb::sql::Thing::from(("person", "tobie")).into());
+        assert_eq!(
+            path.to_string().as_str(),
+            "WHERE ->knows->(person WHERE influencer = false)"
+        );
+        assert_eq!(
+            count.to_string().as_str(),
+            "WHERE ->knows->person CONTAINS person:tobie"
+        );
+    }
+    #[test]
+    fn cond_macro() {
+        let name = "Tobie";
+        let simple = crate::cond!(age > 16);
+        let complex = crate::cond!(age > 16 && name == $name || tags CONTAINS "x");
+        let group = crate::cond!(
+            address.city == name && !(role IN vec!["admin", "owner"] || $auth.role != "viewer")
+        );
+        let not = crate::cond!(!verified && score >= (-1));
+        assert_eq!(
+            simple,
+            Cond::new()
+                .left_easy("age")
+                .op(surrealdb::sql::Operator::MoreThan)
+                .right(16.into())
+        );
+        assert_eq!(
+            complex.to_string().as_str(),
+            "WHERE (age > 16 AND name = $name) OR tags CONTAINS 'x'"
+        );
+        assert_eq!(
+            group.to_string().as_str(),
+            "WHERE address.city = 'Tobie' AND !(role INSIDE ['admin', 'owner'] OR $auth.role != 'viewer')"
+        );
+        assert_eq!(not.to_string().as_str(), "WHERE !verified AND score >= -1");
+    }
+    #[test]
+    fn cond_macro_bare() {
+        let field = crate::cond!(active && age > 16);
+        let param = crate::cond!($auth.admin || (owner.verified && !banned));
+        assert_eq!(field.to_string().as_str(), "WHERE active AND age > 16");
+        assert_eq!(
+            param.to_string().as_str(),
+            "WHERE $auth.admin OR (owner.verified AND !banned)"
+        );
+    }
+    #[test]
+    fn contains() {
+        let contains = Cond::field("tags").contains("rust");
+        let any = Cond::field("tags").contains_any(vec!["rust", "surrealdb"]);
+        let all = Cond::field("tags").contains_all(["rust", "surrealdb"]);
+        let none = Cond::field("tags").contains_none(std::collections::BTreeSet::from([1, 2]));
+        let not = Cond::field("tags").contains_not("go");
+        assert_eq!(contains.to_string().as_str(), "WHERE tags CONTAINS 'rust'");
+        assert_eq!(
+            any.to_string().as_str(),
+            "WHERE tags CONTAINSANY ['rust', 'surrealdb']"
+        );
+        assert_eq!(
+            all.to_string().as_str(),
+            "WHERE tags CONTAINSALL ['rust', 'surrealdb']"
+        );
+        assert_eq!(none.to_string().as_str(), "WHERE tags CONTAINSNONE [1, 2]");
+        assert_eq!(not.to_string().as_str(), "WHERE tags CONTAINSNOT 'go'");
+    }
+    #[test]
+    fn inside() {
+        let inside = Cond::field("role").inside(vec!["admin", "owner"]);
+        let not = Cond::field("role").not_inside(vec!["viewer"]);
+        let all = Cond::field("tags").all_inside(vec!["rust", "go"]);
+        let any = Cond::field("tags").any_inside(vec!["rust"]);
+        let none = Cond::field("tags").none_inside(vec!["java"]);
+        let subquery = Cond::field("id").inside(Value::from(
+            SelectStmt::new()
+                .table("vip".into())
+                .fields(vec![Field::new("person")]),
+        ));
+        assert_eq!(
+            inside.to_string().as_str(),
+            "WHERE role INSIDE ['admin', 'owner']"
+        );
+        assert_eq!(not.to_string().as_str(), "WHERE role NOTINSIDE ['viewer']");
+        assert_eq!(
+            all.to_string().as_str(),
+            "WHERE tags ALLINSIDE ['rust', 'go']"
+        );
+        assert_eq!(any.to_string().as_str(), "WHERE tags ANYINSIDE ['rust']");
+        assert_eq!(none.to_string().as_str(), "WHERE tags NONEINSIDE ['java']");
+        assert_eq!(
+            subquery.to_string().as_str(),
+            "WHERE id INSIDE (SELECT person FROM vip)"
+        );
+    }
+    #[test]
+    fn matches() {
+        let fuzzy = Cond::field("name").fuzzy("tobie");
+        let matches = Cond::field("title")
+            .matches(Some(1), "hello world")
+            .and(Cond::field("body").matches(Some(2), "surrealdb"));
+        let any = Cond::field("title").matches(None, "hello world");
+        assert_eq!(fuzzy.to_string().as_str(), "WHERE name ~ 'tobie'");
+        assert_eq!(any.to_string().as_str(), "WHERE title @@ 'hello world'");
+        assert_eq!(
+            matches.to_string().as_str(),
+            "WHERE title @1@ 'hello world' AND body @2@ 'surrealdb'"
+        );
+    }
 }
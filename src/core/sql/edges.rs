@@ -1,12 +1,15 @@
-use super::SurrealTable;
-use surrealdb::sql::{Dir, Id};
+use super::{Cond, Field, SurrealTable};
+use surrealdb::sql::{self, Dir, Graph, Id, Idiom, Operator, Part, Table, Tables, Value};
 
 /// # Edges
 /// build from dir to target
 /// such as:
 /// - surreal -> hello
 /// - surreal -> surrealdb <-> user
-/// - surreal:db -> user:matt
+/// - surreal:db -> user:matt (rendered as `surreal:db->(user WHERE id = user:matt)`)
+/// - ->knows->(person WHERE influencer = false)
+///
+/// Edges can be used as graph path in WHERE, an empty table on the left side means the path starts from the current record
 /// ## attention
 /// please distinguish surrealdb::sql::Edges (The two are different, but with the same design)
 #[derive(Debug, Clone, PartialEq)]
@@ -20,6 +23,8 @@ pub struct Edges {
     pub from: SurrealTable,
     /// Table | Record on the Right Side of the Connection Edge
     pub to: SurrealTable,
+    /// WHERE of the right side, such as: `->(person WHERE age > 18)`
+    pub cond: Option<Cond>,
 }
 
 impl Edges {
@@ -41,7 +46,92 @@ impl Edges {
     /// assert_eq!(edges.to_string().as_str(),edges_str);
     /// ```
     pub fn new(from: SurrealTable, dir: Dir, to: SurrealTable) -> Self {
-        Edges { dir, from, to }
+        Edges {
+            dir,
+            from,
+            to,
+            cond: None,
+        }
+    }
+    /// ## filter the right side by WHERE
+    /// ### example
+    /// ```
+    /// let edges = Edges::new(
+    ///     Edges::new("".into(), Dir::Out, "knows".into()).into(),
+    ///     Dir::Out,
+    ///     "person".into(),
+    /// )
+    /// .cond(Cond::new().left("influencer").op(Operator::Equal).right(false.into()));
+    /// assert_eq!(
+    ///     edges.to_string().as_str(),
+    ///     "->knows->(person WHERE influencer = false)"
+    /// );
+    /// ```
+    pub fn cond(mut self, cond: Cond) -> Self {
+        self.cond.replace(cond);
+        self
+    }
+    /// ## convert to graph path
+    /// such as: `person:tobie->knows->person`
+    ///
+    /// a record in the path (except the start) is converted to its table filtered by id,
+    /// such as: `surreal:db->(user WHERE id = user:matt)`
+    ///
+    /// return None if `$param` is used in the path (except the start), which is not a graph path
+    pub fn to_idiom(self) -> Option<Idiom> {
+        let (start, steps) = self.flatten();
+        let mut parts = match start {
+            SurrealTable::Table(table) if table.0.is_empty() => vec![],
+            SurrealTable::Table(table) => vec![Part::Field(table.0.into())],
+            start => vec![Part::Start(start.into())],
+        };
+        for (dir, table, cond) in steps {
+            let (what, cond) = match table {
+                SurrealTable::Table(table) => (table, cond),
+                SurrealTable::Thing(thing) => {
+                    let id = Cond::new()
+                        .left("id")
+                        .op(Operator::Equal)
+                        .right(thing.clone().into());
+                    let cond = match cond {
+                        Some(cond) => id.and(cond),
+                        None => id,
+                    };
+                    (Table(thing.tb), Some(cond))
+                }
+                _ => return None,
+            };
+            parts.push(Part::Graph(Graph {
+                dir,
+                what: Tables(vec![what]),
+                cond: cond.map(Cond::to_origin),
+                ..Default::default()
+            }));
+        }
+        Some(Idiom(parts))
+    }
+    /// flatten to the start and the steps: `(dir, table, cond)`
+    fn flatten(self) -> (SurrealTable, Vec<(Dir, SurrealTable, Option<Cond>)>) {
+        let (start, mut steps) = match self.from {
+            SurrealTable::Edges(from) => from.flatten(),
+            from => (from, vec![]),
+        };
+        match self.to {
+            SurrealTable::Edges(to) => {
+                let (to_start, to_steps) = to.flatten();
+                steps.push((self.dir, to_start, None));
+                steps.extend(to_steps);
+            }
+            to => steps.push((self.dir, to, None)),
+        }
+        if let Some(cond) = self.cond {
+            let last = steps.last_mut().unwrap();
+            last.2 = Some(match last.2.take() {
+                Some(inner) => inner.and(cond),
+                None => cond,
+            });
+        }
+        (start, steps)
     }
 }
 
@@ -56,11 +146,7 @@ impl Edges {
 /// ```
 impl From<((&str, Id), Dir, (&str, Id))> for Edges {
     fn from(value: ((&str, Id), Dir, (&str, Id))) -> Self {
-        Edges {
-            dir: value.1,
-            from: value.0.into(),
-            to: value.2.into(),
-        }
+        Edges::new(value.0.into(), value.1, value.2.into())
     }
 }
 
@@ -86,35 +172,65 @@ impl From<(&str, Dir, &str)> for Edges {
 
 impl From<(SurrealTable, Dir, SurrealTable)> for Edges {
     fn from(value: (SurrealTable, Dir, SurrealTable)) -> Self {
-        Edges {
-            dir: value.1,
-            from: value.0,
-            to: value.2,
+        Edges::new(value.0, value.1, value.2)
+    }
+}
+
+/// ## convert to graph path
+/// ### panic
+/// `$param` can not be used in the path (except the start)
+impl From<Edges> for Value {
+    fn from(value: Edges) -> Self {
+        match value.clone().to_idiom() {
+            Some(idiom) => Value::Idiom(idiom),
+            None => panic!("{} cannot be used as graph path", value.to_string()),
         }
     }
 }
 
-// impl From<Edges> for sql::Cond {
-//     fn from(value: Edges) -> Self {
-//         sql::Cond(Value::from(value))
-//     }
-// }
+impl From<Edges> for Field {
+    fn from(value: Edges) -> Self {
+        Field::single_expr(value.into(), None)
+    }
+}
+
+impl From<Edges> for sql::Cond {
+    fn from(value: Edges) -> Self {
+        sql::Cond(Value::from(value))
+    }
+}
 
 impl ToString for Edges {
     fn to_string(&self) -> String {
-        format!(
-            "{}{}{}",
-            self.from.to_string(),
-            self.dir.to_string(),
-            self.to.to_string()
-        )
+        if let Some(idiom) = self.clone().to_idiom() {
+            return idiom.to_string();
+        }
+        // not a graph path, render the flattened steps
+        let (start, steps) = self.clone().flatten();
+        let mut edges = match start {
+            SurrealTable::Table(table) if table.0.is_empty() => String::new(),
+            start => start.to_string(),
+        };
+        for (dir, table, cond) in steps {
+            match cond {
+                Some(cond) => edges.push_str(&format!(
+                    "{}({} {})",
+                    dir,
+                    table.to_string(),
+                    cond.to_string()
+                )),
+                None => edges.push_str(&format!("{}{}", dir, table.to_string())),
+            }
+        }
+        edges
     }
 }
 
 #[cfg(test)]
 mod test_edges {
     use super::Edges;
-    use surrealdb::sql::{Dir, Id};
+    use crate::core::sql::{Cond, Param};
+    use surrealdb::sql::{Dir, Id, Operator, Value};
 
     #[test]
     fn complex_edges() {
@@ -166,4 +282,105 @@ mod test_edges {
         );
         assert_eq!(edges, simple);
     }
+    #[test]
+    fn graph_path() {
+        let edges = Edges::new(
+            Edges::new(("person", "tobie").into(), Dir::Out, "knows".into()).into(),
+            Dir::Out,
+            "person".into(),
+        );
+        let value: Value = edges.clone().into();
+        assert_eq!(value.to_string().as_str(), "person:tobie->knows->person");
+        assert_eq!(value.to_string(), edges.to_string());
+    }
+    #[test]
+    fn graph_cond() {
+        let influencer = Cond::new()
+            .left("influencer")
+            .op(Operator::Equal)
+            .right(false.into());
+        let edges = Edges::new(
+            Edges::new("".into(), Dir::Out, "knows".into()).into(),
+            Dir::Out,
+            "person".into(),
+        )
+        .cond(influencer.clone());
+        let nested = Edges::new(
+            edges.clone().into(),
+            Dir::In,
+            Edges::new("likes".into(), Dir::In, "post".into())
+                .cond(influencer)
+                .into(),
+        );
+        let value: Value = edges.clone().into();
+        assert_eq!(
+            value.to_string().as_str(),
+            "->knows->(person WHERE influencer = false)"
+        );
+        assert_eq!(value.to_string(), edges.to_string());
+        assert_eq!(
+            Value::from(nested).to_string().as_str(),
+            "->knows->(person WHERE influencer = false)<-likes<-(post WHERE influencer = false)"
+        );
+    }
+    #[test]
+    fn record_path() {
+        let edges = Edges::new(("surreal", "db").into(), Dir::Out, ("user", "matt").into());
+        let simple: Edges = (
+            ("surreal", Id::String("db".to_string())),
+            Dir::Out,
+            ("user", Id::Number(15)),
+        )
+            .into();
+        assert_eq!(
+            Value::from(edges).to_string().as_str(),
+            "surreal:db->(user WHERE id = user:matt)"
+        );
+        assert_eq!(
+            Value::from(simple).to_string().as_str(),
+            "surreal:db->(user WHERE id = user:15)"
+        );
+    }
+    #[test]
+    fn record_path_cond() {
+        let edges = Edges::new(
+            Edges::new(("person", "tobie").into(), Dir::Out, "knows".into()).into(),
+            Dir::Out,
+            ("person", "jaime").into(),
+        )
+        .cond(
+            Cond::new()
+                .left("age")
+                .op(Operator::MoreThan)
+                .right(18.into()),
+        );
+        assert_eq!(
+            Value::from(edges).to_string().as_str(),
+            "person:tobie->knows->(person WHERE id = person:jaime AND age > 18)"
+        );
+    }
+    #[test]
+    fn param_path() {
+        let edges = Edges::new("person".into(), Dir::Out, Param::new("friends").into());
+        assert!(edges.clone().to_idiom().is_none());
+        assert_eq!(edges.to_string().as_str(), "person->$friends");
+    }
+    #[test]
+    #[should_panic]
+    fn param_path_value() {
+        let _ = Value::from(Edges::new("".into(), Dir::Out, Param::new("x").into()));
+    }
+    #[test]
+    fn nested_cond() {
+        let edges = Edges::new(
+            "a".into(),
+            Dir::Out,
+            Edges::new("b".into(), Dir::Out, "c".into()).into(),
+        )
+        .cond(Cond::new().left("x").op(Operator::Equal).right(1.into()));
+        let record = Edges::new(("surreal", "db").into(), Dir::Out, ("user", "matt").into());
+        assert_eq!(edges.to_string().as_str(), "a->b->(c WHERE x = 1)");
+        assert_eq!(edges.to_string(), Value::from(edges).to_string());
+        assert_eq!(record.to_string(), Value::from(record).to_string());
+    }
 }
@@ -0,0 +1,69 @@
+use surrealdb::sql::{Param, Part, Value};
+
+use super::Field;
+
+/// ## params in DEFINE EVENT statement
+/// - `$before` : the record before the change
+/// - `$after` : the record after the change
+/// - `$event` : type of the event, `CREATE` | `UPDATE` | `DELETE`
+/// ### example
+/// ```
+/// let email = EventParam::After.field("email");
+/// assert_eq!(email.to_string().as_str(), "$after.email");
+/// let event: Value = EventParam::Event.into();
+/// assert_eq!(event.to_string().as_str(), "$event");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventParam {
+    Before,
+    After,
+    Event,
+}
+
+impl EventParam {
+    /// name of the param without `$`
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventParam::Before => "before",
+            EventParam::After => "after",
+            EventParam::Event => "event",
+        }
+    }
+    /// ## field of the record
+    /// such as: `$before.email` , `$after.address.city`
+    pub fn field(&self, field: &str) -> Field {
+        let mut parts = vec![Part::Start(self.to_value())];
+        parts.extend(Field::from(field).to_idiom().0);
+        parts.into()
+    }
+    pub fn to_value(&self) -> Value {
+        Value::Param(Param::from(self.name()))
+    }
+}
+
+impl From<EventParam> for Value {
+    fn from(value: EventParam) -> Self {
+        value.to_value()
+    }
+}
+
+#[cfg(test)]
+mod test_event_param {
+    use surrealdb::sql::Value;
+
+    use super::EventParam;
+
+    #[test]
+    fn field() {
+        let before = EventParam::Before.field("email");
+        let after = EventParam::After.field("address.city");
+        assert_eq!(before.to_string().as_str(), "$before.email");
+        assert_eq!(after.to_string().as_str(), "$after.address.city");
+    }
+
+    #[test]
+    fn value() {
+        let event: Value = EventParam::Event.into();
+        assert_eq!(event.to_string().as_str(), "$event");
+    }
+}
@@ -63,6 +63,12 @@ impl Field {
             alias: r#as,
         })
     }
+    /// ## build Field from an expression
+    /// such as function call: `fn::greet(name) AS greeting`
+    pub fn single_expr(expr: sql::Value, r#as: Option<&str>) -> Self {
+        let alias = r#as.map(str_to_idiom);
+        Field(sql::Field::Single { expr, alias })
+    }
     /// ## new instance Field
     /// This method has no aliases
     pub fn new(field: &str) -> Self {
@@ -0,0 +1,88 @@
+use surrealdb::sql::{self, Value};
+
+use super::Field;
+
+/// ## Function call
+/// call a builtin function or a custom function defined by `DEFINE FUNCTION`,
+/// which can be used as a projection in SELECT or an operand in WHERE
+/// ### example
+/// ```
+/// let greet = Function::custom("greet", vec![Field::new("name").into()]);
+/// assert_eq!(greet.to_string().as_str(), "fn::greet(name)");
+/// let lower = Function::normal("string::lowercase", vec!["SurrealDB".into()]);
+/// assert_eq!(lower.to_string().as_str(), "string::lowercase('SurrealDB')");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function(sql::Function);
+
+impl Function {
+    /// ## builtin function
+    /// such as: `string::lowercase(@value)` , `math::mean(@array)`
+    pub fn normal(name: &str, args: Vec<Value>) -> Self {
+        Function(sql::Function::Normal(name.to_string(), args))
+    }
+    /// ## custom function
+    /// name with or without `fn::` , such as: `fn::greet(@name)`
+    pub fn custom(name: &str, args: Vec<Value>) -> Self {
+        let name = name.trim_start_matches("fn::");
+        Function(sql::Function::Custom(name.to_string(), args))
+    }
+    /// ## use function as a projection with AS
+    /// `fn::greet(name) AS greeting`
+    pub fn alias(self, r#as: &str) -> Field {
+        Field::single_expr(self.into(), Some(r#as))
+    }
+    pub fn to_origin(self) -> sql::Function {
+        self.0
+    }
+}
+
+impl ToString for Function {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<Function> for Value {
+    fn from(value: Function) -> Self {
+        Value::Function(Box::new(value.to_origin()))
+    }
+}
+
+impl From<Function> for Field {
+    fn from(value: Function) -> Self {
+        Field::single_expr(value.into(), None)
+    }
+}
+
+#[cfg(test)]
+mod test_function {
+    use surrealdb::sql::Operator;
+
+    use crate::core::sql::{Cond, Field};
+
+    use super::Function;
+
+    #[test]
+    fn projection() {
+        let greet = Function::custom("fn::greet", vec![Field::new("name").into()]);
+        let field: Field = greet.clone().into();
+        assert_eq!(field.to_string().as_str(), "fn::greet(name)");
+        assert_eq!(
+            greet.alias("greeting").to_string().as_str(),
+            "fn::greet(name) AS greeting"
+        );
+    }
+
+    #[test]
+    fn cond() {
+        let cond = Cond::new()
+            .left(Function::normal(
+                "string::len",
+                vec![Field::new("name").into()],
+            ))
+            .op(Operator::MoreThan)
+            .right(3.into());
+        assert_eq!(cond.to_string().as_str(), "WHERE string::len(name) > 3");
+    }
+}
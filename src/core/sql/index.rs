@@ -0,0 +1,59 @@
+use serde_json::{json, Value};
+
+/// ## distance function of MTREE index
+/// - EUCLIDEAN (default)
+/// - MANHATTAN
+/// - HAMMING
+/// - MINKOWSKI @order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Distance {
+    #[default]
+    Euclidean,
+    Manhattan,
+    Hamming,
+    Minkowski(i64),
+}
+
+/// ## vector type of MTREE index
+/// - F64 (default)
+/// - F32
+/// - I64
+/// - I32
+/// - I16
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VectorType {
+    #[default]
+    F64,
+    F32,
+    I64,
+    I32,
+    I16,
+}
+
+/// `surrealdb::sql::index::Distance` is not exported,
+/// so convert to the serialized form of it
+impl From<Distance> for Value {
+    fn from(value: Distance) -> Self {
+        match value {
+            Distance::Euclidean => json!("Euclidean"),
+            Distance::Manhattan => json!("Manhattan"),
+            Distance::Hamming => json!("Hamming"),
+            Distance::Minkowski(order) => json!({ "Minkowski": { "Int": order } }),
+        }
+    }
+}
+
+/// `surrealdb::sql::index::VectorType` is not exported,
+/// so convert to the serialized form of it
+impl From<VectorType> for Value {
+    fn from(value: VectorType) -> Self {
+        let vector_type = match value {
+            VectorType::F64 => "F64",
+            VectorType::F32 => "F32",
+            VectorType::I64 => "I64",
+            VectorType::I32 => "I32",
+            VectorType::I16 => "I16",
+        };
+        json!(vector_type)
+    }
+}
@@ -0,0 +1,110 @@
+use surrealdb::sql::{self, Table};
+
+/// ## Kind
+/// the type of a field or a param, such as:
+/// - string
+/// - option<string>
+/// - record<user | admin>
+/// - array<int, 10>
+///
+/// simple types can be converted from `surrealdb::sql::Kind` directly,
+/// nested types are recommended to be built by methods
+/// ### example
+/// ```
+/// let kind: Kind = sql::Kind::String.into();
+/// assert_eq!(kind.to_string().as_str(), "string");
+/// let kind = Kind::option(Kind::record(vec!["user", "admin"]));
+/// assert_eq!(kind.to_string().as_str(), "option<record<user | admin>>");
+/// let kind = Kind::array(sql::Kind::Int, Some(10));
+/// assert_eq!(kind.to_string().as_str(), "array<int, 10>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Kind(sql::Kind);
+
+impl Kind {
+    /// ## option<@kind>
+    pub fn option(kind: impl Into<Kind>) -> Self {
+        Kind(sql::Kind::Option(Box::new(kind.into().to_origin())))
+    }
+    /// ## record<@table | ...>
+    /// no tables means any record: `record`
+    pub fn record(tables: Vec<&str>) -> Self {
+        Kind(sql::Kind::Record(
+            tables.into_iter().map(Table::from).collect::<Vec<Table>>(),
+        ))
+    }
+    /// ## geometry<@type | ...>
+    /// such as: point, line, polygon, multipoint, multiline, multipolygon, collection
+    pub fn geometry(types: Vec<&str>) -> Self {
+        Kind(sql::Kind::Geometry(
+            types
+                .into_iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>(),
+        ))
+    }
+    /// ## array<@kind, @max_len>
+    pub fn array(kind: impl Into<Kind>, max: Option<u64>) -> Self {
+        Kind(sql::Kind::Array(Box::new(kind.into().to_origin()), max))
+    }
+    /// ## set<@kind, @max_len>
+    pub fn set(kind: impl Into<Kind>, max: Option<u64>) -> Self {
+        Kind(sql::Kind::Set(Box::new(kind.into().to_origin()), max))
+    }
+    /// ## @kind | @kind ...
+    pub fn either(kinds: Vec<Kind>) -> Self {
+        Kind(sql::Kind::Either(
+            kinds
+                .into_iter()
+                .map(|x| x.to_origin())
+                .collect::<Vec<sql::Kind>>(),
+        ))
+    }
+    pub fn to_origin(self) -> sql::Kind {
+        self.0
+    }
+}
+
+impl ToString for Kind {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<sql::Kind> for Kind {
+    fn from(value: sql::Kind) -> Self {
+        Kind(value)
+    }
+}
+
+impl From<Kind> for sql::Kind {
+    fn from(value: Kind) -> Self {
+        value.to_origin()
+    }
+}
+
+#[cfg(test)]
+mod test_kind {
+    use surrealdb::sql;
+
+    use super::Kind;
+
+    #[test]
+    fn simple() {
+        let kind: Kind = sql::Kind::String.into();
+        assert_eq!(kind.to_string().as_str(), "string");
+        assert_eq!(Kind::record(vec![]).to_string().as_str(), "record");
+    }
+
+    #[test]
+    fn nested() {
+        let option = Kind::option(Kind::record(vec!["user", "admin"]));
+        let array = Kind::array(sql::Kind::Int, Some(10));
+        let set = Kind::set(Kind::option(sql::Kind::String), None);
+        let either = Kind::either(vec![sql::Kind::Int.into(), Kind::geometry(vec!["point"])]);
+        assert_eq!(option.to_string().as_str(), "option<record<user | admin>>");
+        assert_eq!(array.to_string().as_str(), "array<int, 10>");
+        assert_eq!(set.to_string().as_str(), "set<option<string>>");
+        assert_eq!(either.to_string().as_str(), "int | geometry<point>");
+    }
+}
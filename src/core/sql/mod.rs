@@ -1,17 +1,33 @@
+/// extend TOKENIZERS and FILTERS in DEFINE ANALYZER statement
+mod analyzer;
 /// extend WHERE sub query
 mod cond;
 /// extend create data part in CREATE statement
 mod create;
 /// extend relate edges
 mod edges;
+/// extend params in DEFINE EVENT statement
+mod event;
 /// extend field part
 mod field;
+/// extend function call in fields and conditions
+mod function;
+/// extend params of MTREE index in DEFINE INDEX statement
+mod index;
 /// extend insert data part in INSERT statement
 mod insert;
+/// extend TYPE part of field and param
+mod kind;
 /// extend ORDER BY sub query
 mod order;
+/// extend $param defined by LET statement
+mod param;
 /// extend JSON PATCH in UPDATE statement
 mod patch;
+/// extend PERMISSIONS part in DEFINE statement
+mod permission;
+/// extend ROLES in DEFINE USER statement
+mod role;
 /// extend SET sub query，result: a = b
 mod set_field;
 /// extend how to express SurrealDB Table in statements
@@ -19,13 +35,21 @@ mod table;
 /// extend update data part in UPDATE statement
 mod update;
 
+pub use analyzer::{Filter, Language, Tokenizer};
 pub use cond::Cond;
 pub use create::CreateData;
 pub use edges::Edges;
+pub use event::EventParam;
 pub use field::Field;
+pub use function::Function;
+pub use index::{Distance, VectorType};
 pub use insert::InsertData;
+pub use kind::Kind;
 pub use order::Order;
+pub use param::Param;
 pub use patch::PatchOp;
+pub use permission::Permissions;
+pub use role::Role;
 pub use set_field::SetField;
 pub use table::SurrealTable;
 pub use update::UpdateData;
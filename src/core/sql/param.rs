@@ -0,0 +1,101 @@
+use surrealdb::sql::{self, Part, Value};
+
+use super::{Field, SurrealTable};
+
+/// ## `$param` defined by LET statement or DEFINE PARAM statement
+/// can be used anywhere a `Value` or `SurrealTable` is accepted
+/// ### example
+/// ```
+/// let adults = Param::new("$adults");
+/// let name = adults.field("name");
+/// assert_eq!(name.to_string().as_str(), "$adults.name");
+/// let select = Stmt::select().table(adults.into()).field_all();
+/// assert_eq!(select.to_string().as_str(), "SELECT * FROM $adults");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param(sql::Param);
+
+impl Param {
+    /// ## create param
+    /// name with or without `$`
+    pub fn new(name: &str) -> Self {
+        Param(sql::Param::from(name.trim_start_matches('$')))
+    }
+    /// name of the param without `$`
+    pub fn name(&self) -> &str {
+        self.0 .0 .0.as_str()
+    }
+    /// ## field of the param
+    /// such as: `$user.email` , `$user.address.city`
+    pub fn field(&self, field: &str) -> Field {
+        let mut parts = vec![Part::Start(self.to_value())];
+        parts.extend(Field::from(field).to_idiom().0);
+        parts.into()
+    }
+    pub fn to_value(&self) -> Value {
+        Value::Param(self.0.clone())
+    }
+    pub fn to_origin(self) -> sql::Param {
+        self.0
+    }
+}
+
+impl ToString for Param {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<&str> for Param {
+    fn from(value: &str) -> Self {
+        Param::new(value)
+    }
+}
+
+impl From<Param> for Value {
+    fn from(value: Param) -> Self {
+        Value::Param(value.0)
+    }
+}
+
+impl From<Param> for Field {
+    fn from(value: Param) -> Self {
+        Field::single_expr(value.into(), None)
+    }
+}
+
+impl From<Param> for SurrealTable {
+    fn from(value: Param) -> Self {
+        SurrealTable::Param(value.0)
+    }
+}
+
+#[cfg(test)]
+mod test_param {
+    use surrealdb::sql::Value;
+
+    use crate::core::sql::SurrealTable;
+
+    use super::Param;
+
+    #[test]
+    fn simple() {
+        let param = Param::new("$adults");
+        let value: Value = param.clone().into();
+        let table: SurrealTable = param.clone().into();
+        assert_eq!(param.name(), "adults");
+        assert_eq!(value.to_string().as_str(), "$adults");
+        assert_eq!(table.to_string().as_str(), "$adults");
+        assert_eq!(Value::from(table).to_string().as_str(), "$adults");
+    }
+
+    #[test]
+    fn field() {
+        let param = Param::new("user");
+        assert_eq!(param.field("email").to_string().as_str(), "$user.email");
+        assert_eq!(
+            param.field("address.city").to_string().as_str(),
+            "$user.address.city"
+        );
+    }
+}
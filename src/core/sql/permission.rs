@@ -0,0 +1,116 @@
+use surrealdb::sql::{self, Permission};
+
+use super::Cond;
+
+/// ## PERMISSIONS
+/// build PERMISSIONS part in DEFINE statement
+/// ```
+/// PERMISSIONS [ NONE | FULL
+///     | FOR select @expression
+///     | FOR create @expression
+///     | FOR update @expression
+///     | FOR delete @expression
+/// ]
+/// ```
+/// each operation accept `Cond` (FOR @op WHERE @cond) or `surrealdb::sql::Permission`
+/// ### example
+/// ```
+/// let permissions = Permissions::none()
+///     .select(Permission::Full)
+///     .update(Cond::new().left("user").op(Operator::Equal).right(Value::Param("auth".into())));
+/// assert_eq!(
+///     permissions.to_string().as_str(),
+///     "PERMISSIONS FOR select FULL, FOR create, delete NONE, FOR update WHERE user = $auth"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Permissions(sql::Permissions);
+
+impl Permissions {
+    /// ## PERMISSIONS FULL
+    /// default
+    pub fn full() -> Self {
+        Permissions(sql::Permissions::full())
+    }
+    /// ## PERMISSIONS NONE
+    pub fn none() -> Self {
+        Permissions(sql::Permissions::none())
+    }
+    /// ## FOR select
+    pub fn select(mut self, permission: impl Into<Permission>) -> Self {
+        self.0.select = permission.into();
+        self
+    }
+    /// ## FOR create
+    pub fn create(mut self, permission: impl Into<Permission>) -> Self {
+        self.0.create = permission.into();
+        self
+    }
+    /// ## FOR update
+    pub fn update(mut self, permission: impl Into<Permission>) -> Self {
+        self.0.update = permission.into();
+        self
+    }
+    /// ## FOR delete
+    pub fn delete(mut self, permission: impl Into<Permission>) -> Self {
+        self.0.delete = permission.into();
+        self
+    }
+    pub fn to_origin(self) -> sql::Permissions {
+        self.0
+    }
+}
+
+impl ToString for Permissions {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<Permissions> for sql::Permissions {
+    fn from(value: Permissions) -> Self {
+        value.to_origin()
+    }
+}
+
+impl From<sql::Permissions> for Permissions {
+    fn from(value: sql::Permissions) -> Self {
+        Permissions(value)
+    }
+}
+
+/// Cond -> Permission::Specific
+impl From<Cond> for Permission {
+    fn from(value: Cond) -> Self {
+        Permission::Specific(value.to_origin().0)
+    }
+}
+
+#[cfg(test)]
+mod test_permissions {
+    use surrealdb::sql::{Operator, Permission, Value};
+
+    use crate::core::sql::Cond;
+
+    use super::Permissions;
+
+    #[test]
+    fn full_none() {
+        assert_eq!(Permissions::full().to_string().as_str(), "PERMISSIONS FULL");
+        assert_eq!(Permissions::none().to_string().as_str(), "PERMISSIONS NONE");
+    }
+
+    #[test]
+    fn specific() {
+        let permissions = Permissions::none().select(Permission::Full).update(
+            Cond::new()
+                .left("user")
+                .op(Operator::Equal)
+                .right(Value::Param("auth".into())),
+        );
+        assert_eq!(
+            permissions.to_string().as_str(),
+            "PERMISSIONS FOR select FULL, FOR create, delete NONE, FOR update WHERE user = $auth"
+        );
+    }
+}
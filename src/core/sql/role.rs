@@ -0,0 +1,24 @@
+use surrealdb::sql::Ident;
+
+/// ## ROLES of DEFINE USER
+/// - OWNER : can view and edit any resource and manage users
+/// - EDITOR : can view and edit any resource except users
+/// - VIEWER : can view any resource (default)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Role {
+    Owner,
+    Editor,
+    #[default]
+    Viewer,
+}
+
+impl From<Role> for Ident {
+    fn from(value: Role) -> Self {
+        let role = match value {
+            Role::Owner => "Owner",
+            Role::Editor => "Editor",
+            Role::Viewer => "Viewer",
+        };
+        Ident::from(role)
+    }
+}
@@ -89,6 +89,8 @@ impl ToString for SetField {
 
 #[cfg(test)]
 mod test_set_field {
+    use crate::core::{create::CreateStmt, select::SelectStmt, sql::CreateData};
+
     use super::SetField;
 
     #[test]
@@ -103,6 +105,32 @@ mod test_set_field {
         assert_eq!(sf.to_string().as_str(), "name = 'Matt'");
     }
 
+    #[test]
+    fn subquery() {
+        let friends = SetField::new(
+            "friends",
+            None,
+            SelectStmt::new()
+                .table("person".into())
+                .fields(vec!["id".into()]),
+        );
+        let author = SetField::new(
+            "author",
+            None,
+            CreateStmt::new()
+                .table("author".into())
+                .data(CreateData::set().push(("name", "Tobie").into())),
+        );
+        assert_eq!(
+            friends.to_string().as_str(),
+            "friends = (SELECT id FROM person)"
+        );
+        assert_eq!(
+            author.to_string().as_str(),
+            "author = (CREATE author SET name = 'Tobie')"
+        );
+    }
+
     #[test]
     fn default() {
         let s_f = SetField::default();
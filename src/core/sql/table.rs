@@ -1,11 +1,12 @@
 use super::Edges;
 
-use surrealdb::sql::{Id, Table, Thing, Value, Values};
+use surrealdb::sql::{Id, Param, Table, Thing, Value, Values};
 
 /// # create SurrealDB Table
 /// 1. Regular tables: Table
 /// 2. Directly declare a table with an Id: Thing
 /// 3. table relate with other table: Edges
+/// 4. `$param` which holds tables or records: Param
 #[derive(Debug, Clone, PartialEq)]
 pub enum SurrealTable {
     // not recommend :`Strand(Strand)`
@@ -18,6 +19,8 @@ pub enum SurrealTable {
     /// 2. {{ATable}}->{{BTable}}<-{{CTable}}
     /// 3. ...
     Edges(Box<Edges>),
+    /// param such as: `$adults` (defined by LET statement)
+    Param(Param),
 }
 
 pub trait IntoTable: Sized {
@@ -109,6 +112,7 @@ impl ToString for SurrealTable {
             SurrealTable::Table(table) => table.to_string(),
             SurrealTable::Thing(thing) => thing.to_string(),
             SurrealTable::Edges(edges) => edges.to_string(),
+            SurrealTable::Param(param) => param.to_string(),
         }
     }
 }
@@ -118,7 +122,8 @@ impl From<SurrealTable> for Value {
         match value {
             SurrealTable::Table(table) => table.into(),
             SurrealTable::Thing(thing) => thing.into(),
-            SurrealTable::Edges(edges) => edges.to_string().into(),
+            SurrealTable::Edges(edges) => (*edges).into(),
+            SurrealTable::Param(param) => param.into(),
         }
     }
 }
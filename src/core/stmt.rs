@@ -1,9 +1,31 @@
 use super::create::CreateStmt;
+use super::define::{
+    DefineAnalyzerStmt, DefineDatabaseStmt, DefineEventStmt, DefineFieldStmt, DefineFunctionStmt,
+    DefineIndexStmt, DefineNamespaceStmt, DefineParamStmt, DefineScopeStmt, DefineTableStmt,
+    DefineTokenStmt, DefineUserStmt,
+};
 use super::delete::DeleteStmt;
+use super::ifelse::IfElseStmt;
+use super::info::InfoStmt;
 use super::insert::InsertStmt;
+use super::kill::KillStmt;
+use super::live::LiveSelectStmt;
+use super::r#break::BreakStmt;
+use super::r#continue::ContinueStmt;
+use super::r#for::ForStmt;
+use super::r#let::LetStmt;
+use super::r#return::ReturnStmt;
 use super::r#use::UseStmt;
+use super::relate::RelateStmt;
+use super::remove::Remove;
 use super::select::SelectStmt;
+use super::show::ShowChangesStmt;
+use super::sleep::SleepStmt;
+use super::sql::{Cond, Field, SurrealTable};
+use super::throw::ThrowStmt;
+use super::transaction::TransactionStmt;
 use super::update::UpdateStmt;
+use surrealdb::sql::{Duration, Uuid, Value};
 pub struct Stmt;
 
 impl Stmt {
@@ -116,15 +138,355 @@ impl Stmt {
     pub fn select() -> SelectStmt {
         SelectStmt::new()
     }
+    /// ## relate statement
+    /// ### example
+    /// ```
+    /// let relate = Stmt::relate()
+    ///     .from(("person", "tobie").into())
+    ///     .edge("wrote".into())
+    ///     .to(("article", "surreal").into())
+    ///     .data(CreateData::set().push(SetField::new("time.written", None, "2024-01-28")))
+    ///     .output(surrealdb::sql::Output::None);
+    /// assert_eq!(
+    ///     relate.to_string().as_str(),
+    ///     "RELATE person:tobie -> wrote -> article:surreal SET time.written = '2024-01-28' RETURN NONE"
+    /// );
+    /// ```
+    pub fn relate() -> RelateStmt {
+        RelateStmt::new()
+    }
+    /// ## transaction (BEGIN | COMMIT | CANCEL)
+    /// ### example
+    /// ```
+    /// let transaction = Stmt::transaction()
+    ///     .push(Stmt::create().table(("person", "tobie").into()))
+    ///     .push(Stmt::delete().table(("person", "jaime").into()))
+    ///     .cancel();
+    /// assert_eq!(
+    ///     transaction.to_string().as_str(),
+    ///     "BEGIN TRANSACTION;\nCREATE person:tobie;\nDELETE person:jaime;\nCANCEL TRANSACTION;"
+    /// );
+    /// ```
+    pub fn transaction() -> TransactionStmt {
+        TransactionStmt::new()
+    }
+    /// ## define table statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_table("person")
+    ///     .schemafull()
+    ///     .permissions(Permissions::full());
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE TABLE person SCHEMAFULL PERMISSIONS FULL"
+    /// );
+    /// ```
+    pub fn define_table(name: &str) -> DefineTableStmt {
+        DefineTableStmt::new(name)
+    }
+    /// ## define field statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_field("age", "person".into())
+    ///     .kind(Kind::option(surrealdb::sql::Kind::Int))
+    ///     .default(18);
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE FIELD age ON person TYPE option<int> DEFAULT 18 PERMISSIONS FULL"
+    /// );
+    /// ```
+    pub fn define_field(field: impl Into<Field>, table: SurrealTable) -> DefineFieldStmt {
+        DefineFieldStmt::new(field, table)
+    }
+    /// ## define index statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_index("userEmailIndex", "user".into())
+    ///     .fields(vec![Field::new("email")])
+    ///     .unique();
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE INDEX userEmailIndex ON user FIELDS email UNIQUE"
+    /// );
+    /// ```
+    pub fn define_index(name: &str, table: SurrealTable) -> DefineIndexStmt {
+        DefineIndexStmt::new(name, table)
+    }
+    /// ## define event statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_event("log", "user".into())
+    ///     .when(
+    ///         Cond::new()
+    ///             .left_value(EventParam::Event.into())
+    ///             .op(Operator::Equal)
+    ///             .right("DELETE".into()),
+    ///     )
+    ///     .then(Stmt::create().table("log".into()));
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE EVENT log ON user WHEN $event = 'DELETE' THEN (CREATE log)"
+    /// );
+    /// ```
+    pub fn define_event(name: &str, table: SurrealTable) -> DefineEventStmt {
+        DefineEventStmt::new(name, table)
+    }
+    /// ## define function statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_function("greet")
+    ///     .arg("name", surrealdb::sql::Kind::String)
+    ///     .value(Value::Param("name".into()));
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE FUNCTION fn::greet($name: string) { $name } PERMISSIONS FULL"
+    /// );
+    /// ```
+    pub fn define_function(name: &str) -> DefineFunctionStmt {
+        DefineFunctionStmt::new(name)
+    }
+    /// ## define analyzer statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_analyzer("example_ascii")
+    ///     .tokenizers(vec![Tokenizer::Class])
+    ///     .filters(vec![Filter::Ascii]);
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE ANALYZER example_ascii TOKENIZERS CLASS FILTERS ASCII"
+    /// );
+    /// ```
+    pub fn define_analyzer(name: &str) -> DefineAnalyzerStmt {
+        DefineAnalyzerStmt::new(name)
+    }
+    /// ## define scope statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_scope("account")
+    ///     .session(Duration::from_hours(24))
+    ///     .signin(Stmt::select().table("user".into()).field_all());
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE SCOPE account SESSION 1d SIGNIN (SELECT * FROM user)"
+    /// );
+    /// ```
+    pub fn define_scope(name: &str) -> DefineScopeStmt {
+        DefineScopeStmt::new(name)
+    }
+    /// ## define user statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_user("username")
+    ///     .on_ns()
+    ///     .passhash("$argon2id$...")
+    ///     .roles(vec![Role::Editor]);
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE USER username ON NAMESPACE PASSHASH '$argon2id$...' ROLES EDITOR"
+    /// );
+    /// ```
+    pub fn define_user(name: &str) -> DefineUserStmt {
+        DefineUserStmt::new(name)
+    }
+    /// ## define token statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_token("token_name")
+    ///     .on_db()
+    ///     .kind(Algorithm::Hs256)
+    ///     .value("secret");
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE TOKEN token_name ON DATABASE TYPE HS256 VALUE 'secret'"
+    /// );
+    /// ```
+    pub fn define_token(name: &str) -> DefineTokenStmt {
+        DefineTokenStmt::new(name)
+    }
+    /// ## define namespace statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_ns("abcum");
+    /// assert_eq!(define.to_string().as_str(), "DEFINE NAMESPACE abcum");
+    /// ```
+    pub fn define_ns(name: &str) -> DefineNamespaceStmt {
+        DefineNamespaceStmt::new(name)
+    }
+    /// ## define database statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_db("app_vitalsense").changefeed(Duration::from_days(7));
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE DATABASE app_vitalsense CHANGEFEED 1w"
+    /// );
+    /// ```
+    pub fn define_db(name: &str) -> DefineDatabaseStmt {
+        DefineDatabaseStmt::new(name)
+    }
+    /// ## define param statement
+    /// ### example
+    /// ```
+    /// let define = Stmt::define_param("endpointBase", "https://dummyjson.com");
+    /// assert_eq!(
+    ///     define.to_string().as_str(),
+    ///     "DEFINE PARAM $endpointBase VALUE 'https://dummyjson.com' PERMISSIONS FULL"
+    /// );
+    /// ```
+    pub fn define_param(name: &str, value: impl Into<Value>) -> DefineParamStmt {
+        DefineParamStmt::new(name, value)
+    }
+    /// ## remove statement
+    /// ### example
+    /// ```
+    /// let remove = Stmt::remove().index("userEmailIndex", "user".into());
+    /// assert_eq!(
+    ///     remove.to_string().as_str(),
+    ///     "REMOVE INDEX userEmailIndex ON user"
+    /// );
+    /// ```
+    pub fn remove() -> Remove {
+        Remove::new()
+    }
+    /// ## info statement
+    /// ### example
+    /// ```
+    /// let info = Stmt::info().table("user".into());
+    /// assert_eq!(info.to_string().as_str(), "INFO FOR TABLE user");
+    /// ```
+    pub fn info() -> InfoStmt {
+        InfoStmt::new()
+    }
+    /// ## live select statement
+    /// ### example
+    /// ```
+    /// let live = Stmt::live_select().table("person".into()).diff();
+    /// assert_eq!(live.to_string().as_str(), "LIVE SELECT DIFF FROM person");
+    /// ```
+    pub fn live_select() -> LiveSelectStmt {
+        LiveSelectStmt::new()
+    }
+    /// ## kill statement
+    /// ### example
+    /// ```
+    /// let kill = Stmt::kill(Uuid::try_from("0189d6e3-8eac-703a-9a48-d9faa78b44b9").unwrap());
+    /// assert_eq!(
+    ///     kill.to_string().as_str(),
+    ///     "KILL '0189d6e3-8eac-703a-9a48-d9faa78b44b9'"
+    /// );
+    /// ```
+    pub fn kill(id: impl Into<Uuid>) -> KillStmt {
+        KillStmt::new(id)
+    }
+    /// ## let statement
+    /// ### example
+    /// ```
+    /// let name = Stmt::r#let("name", "tobie");
+    /// assert_eq!(name.to_string().as_str(), "LET $name = 'tobie'");
+    /// ```
+    pub fn r#let(name: &str, value: impl Into<Value>) -> LetStmt {
+        LetStmt::new(name, value)
+    }
+    /// ## if else statement
+    /// ### example
+    /// ```
+    /// let ifelse = Stmt::r#if(
+    ///     Cond::new()
+    ///         .left_value(Param::new("user").into())
+    ///         .op(Operator::Equal)
+    ///         .right(Value::None),
+    /// )
+    /// .then(Stmt::create().table(("user", "tobie").into()));
+    /// assert_eq!(
+    ///     ifelse.to_string().as_str(),
+    ///     "IF $user = NONE { CREATE user:tobie; }"
+    /// );
+    /// ```
+    pub fn r#if(cond: Cond) -> IfElseStmt {
+        IfElseStmt::new(cond)
+    }
+    /// ## for statement
+    /// ### example
+    /// ```
+    /// let names = Stmt::r#for("name", vec!["Tobie", "Jaime"]);
+    /// let names = names.push(Stmt::create().table("person".into()).data(
+    ///     CreateData::set().push(SetField::new("name", None, names.param())),
+    /// ));
+    /// assert_eq!(
+    ///     names.to_string().as_str(),
+    ///     "FOR $name IN ['Tobie', 'Jaime'] { CREATE person SET name = $name; }"
+    /// );
+    /// ```
+    pub fn r#for(name: &str, range: impl Into<Value>) -> ForStmt {
+        ForStmt::new(name, range)
+    }
+    /// ## break statement
+    /// ### example
+    /// ```
+    /// assert_eq!(Stmt::r#break().to_string().as_str(), "BREAK");
+    /// ```
+    pub fn r#break() -> BreakStmt {
+        BreakStmt::new()
+    }
+    /// ## continue statement
+    /// ### example
+    /// ```
+    /// assert_eq!(Stmt::r#continue().to_string().as_str(), "CONTINUE");
+    /// ```
+    pub fn r#continue() -> ContinueStmt {
+        ContinueStmt::new()
+    }
+    /// ## return statement
+    /// ### example
+    /// ```
+    /// let r#return = Stmt::r#return(Param::new("person").field("name"));
+    /// assert_eq!(r#return.to_string().as_str(), "RETURN $person.name");
+    /// ```
+    pub fn r#return(value: impl Into<Value>) -> ReturnStmt {
+        ReturnStmt::new(value)
+    }
+    /// ## throw statement
+    /// ### example
+    /// ```
+    /// let throw = Stmt::throw("Some error message");
+    /// assert_eq!(throw.to_string().as_str(), "THROW 'Some error message'");
+    /// ```
+    pub fn throw(error: impl Into<Value>) -> ThrowStmt {
+        ThrowStmt::new(error)
+    }
+    /// ## sleep statement
+    /// ### example
+    /// ```
+    /// let sleep = Stmt::sleep(Duration::from_millis(500));
+    /// assert_eq!(sleep.to_string().as_str(), "SLEEP 500ms");
+    /// ```
+    pub fn sleep(duration: Duration) -> SleepStmt {
+        SleepStmt::new(duration)
+    }
+    /// ## show changes statement
+    /// ### example
+    /// ```
+    /// let show = Stmt::show_changes("reading".into()).since_versionstamp(1).limit(10);
+    /// assert_eq!(
+    ///     show.to_string().as_str(),
+    ///     "SHOW CHANGES FOR TABLE reading SINCE 1 LIMIT 10"
+    /// );
+    /// ```
+    pub fn show_changes(table: SurrealTable) -> ShowChangesStmt {
+        ShowChangesStmt::new().table(table)
+    }
 }
 
 #[cfg(test)]
 mod test_stmt {
-    use surrealdb::sql::Duration;
+    use surrealdb::sql::{Duration, Value};
 
-    use crate::core::sql::{Cond, CreateData, Field, InsertData, SetField, UpdateData};
+    use crate::core::sql::{
+        Cond, CreateData, EventParam, Field, Filter, InsertData, Kind, Param, Permissions, Role,
+        SetField, Tokenizer, UpdateData,
+    };
 
-    use super::Stmt;
+    use super::{ForStmt, LetStmt, Stmt};
     #[test]
     fn test_select() {
         let select = Stmt::select()
@@ -213,4 +575,322 @@ mod test_stmt {
             "CREATE person:matt1008 SET age = 46 RETURN BEFORE TIMEOUT 15ms PARALLEL"
         );
     }
+    #[test]
+    fn test_relate() {
+        let relate = Stmt::relate()
+            .from(("person", "tobie").into())
+            .edge("wrote".into())
+            .to(("article", "surreal").into())
+            .data(CreateData::set().push(SetField::new("time.written", None, "2024-01-28")))
+            .output(surrealdb::sql::Output::None);
+        assert_eq!(
+            relate.to_string().as_str(),
+            "RELATE person:tobie -> wrote -> article:surreal SET time.written = '2024-01-28' RETURN NONE"
+        );
+    }
+    #[test]
+    fn test_transaction() {
+        let transaction = Stmt::transaction()
+            .push(Stmt::create().table(("person", "tobie").into()))
+            .push(Stmt::delete().table(("person", "jaime").into()))
+            .cancel();
+        assert_eq!(
+            transaction.to_string().as_str(),
+            "BEGIN TRANSACTION;\nCREATE person:tobie;\nDELETE person:jaime;\nCANCEL TRANSACTION;"
+        );
+    }
+    #[test]
+    fn test_define_table() {
+        let define = Stmt::define_table("person")
+            .schemafull()
+            .permissions(Permissions::full());
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE TABLE person SCHEMAFULL PERMISSIONS FULL"
+        );
+    }
+    #[test]
+    fn test_define_field() {
+        let define = Stmt::define_field("age", "person".into())
+            .kind(Kind::option(surrealdb::sql::Kind::Int))
+            .default(18);
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE FIELD age ON person TYPE option<int> DEFAULT 18 PERMISSIONS FULL"
+        );
+    }
+    #[test]
+    fn test_define_index() {
+        let define = Stmt::define_index("userEmailIndex", "user".into())
+            .fields(vec![Field::new("email")])
+            .unique();
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE INDEX userEmailIndex ON user FIELDS email UNIQUE"
+        );
+    }
+    #[test]
+    fn test_define_event() {
+        let define = Stmt::define_event("log", "user".into())
+            .when(
+                Cond::new()
+                    .left_value(EventParam::Event.into())
+                    .op(surrealdb::sql::Operator::Equal)
+                    .right("DELETE".into()),
+            )
+            .then(Stmt::create().table("log".into()));
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE EVENT log ON user WHEN $event = 'DELETE' THEN (CREATE log)"
+        );
+    }
+    #[test]
+    fn test_define_function() {
+        let define = Stmt::define_function("greet")
+            .arg("name", surrealdb::sql::Kind::String)
+            .value(surrealdb::sql::Value::Param("name".into()));
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE FUNCTION fn::greet($name: string) { $name } PERMISSIONS FULL"
+        );
+    }
+    #[test]
+    fn test_define_analyzer() {
+        let define = Stmt::define_analyzer("example_ascii")
+            .tokenizers(vec![Tokenizer::Class])
+            .filters(vec![Filter::Ascii]);
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE ANALYZER example_ascii TOKENIZERS CLASS FILTERS ASCII"
+        );
+    }
+    #[test]
+    fn test_define_scope() {
+        let define = Stmt::define_scope("account")
+            .session(Duration::from_hours(24))
+            .signin(Stmt::select().table("user".into()).field_all());
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE SCOPE account SESSION 1d SIGNIN (SELECT * FROM user)"
+        );
+    }
+    #[test]
+    fn test_define_user() {
+        let define = Stmt::define_user("username")
+            .on_ns()
+            .passhash("$argon2id$...")
+            .roles(vec![Role::Editor]);
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE USER username ON NAMESPACE PASSHASH '$argon2id$...' ROLES EDITOR"
+        );
+    }
+    #[test]
+    fn test_define_token() {
+        let define = Stmt::define_token("token_name")
+            .on_db()
+            .kind(surrealdb::sql::Algorithm::Hs256)
+            .value("secret");
+        assert_eq!(
+            define.to_string().as_str(),
+            "DEFINE TOKEN token_name ON DATABASE TYPE HS256 VALUE 'secret'"
+        );
+    }
+    #[test]
+    fn test_define_tenant() {
+        let tenant = Stmt::transaction()
+            .push(Stmt::define_ns("abcum"))
+            .push(Stmt::r#use().ns("abcum"))
+            .push(Stmt::define_db("app_vitalsense").changefeed(Duration::from_days(7)))
+            .push(Stmt::r#use().ns("abcum").db("app_vitalsense"))
+            .push(Stmt::define_param("endpointBase", "https://dummyjson.com"));
+        assert_eq!(
+            tenant.to_string().as_str(),
+            "BEGIN TRANSACTION;\nDEFINE NAMESPACE abcum;\nUSE NS abcum;\nDEFINE DATABASE app_vitalsense CHANGEFEED 1w;\nUSE NS abcum DB app_vitalsense;\nDEFINE PARAM $endpointBase VALUE 'https://dummyjson.com' PERMISSIONS FULL;\nCOMMIT TRANSACTION;"
+        );
+    }
+    #[test]
+    fn test_remove() {
+        let remove = Stmt::remove().index("userEmailIndex", "user".into());
+        assert_eq!(
+            remove.to_string().as_str(),
+            "REMOVE INDEX userEmailIndex ON user"
+        );
+    }
+    #[test]
+    fn test_info() {
+        let info = Stmt::info().table("user".into());
+        assert_eq!(info.to_string().as_str(), "INFO FOR TABLE user");
+    }
+    #[test]
+    fn test_live_select() {
+        let live = Stmt::live_select().table("person".into()).diff();
+        assert_eq!(live.to_string().as_str(), "LIVE SELECT DIFF FROM person");
+    }
+    #[test]
+    fn test_kill() {
+        let kill = Stmt::kill(
+            surrealdb::sql::Uuid::try_from("0189d6e3-8eac-703a-9a48-d9faa78b44b9").unwrap(),
+        );
+        assert_eq!(
+            kill.to_string().as_str(),
+            "KILL '0189d6e3-8eac-703a-9a48-d9faa78b44b9'"
+        );
+    }
+    #[test]
+    fn test_let() {
+        let adults = LetStmt::subquery(
+            "adults",
+            Stmt::select().table("person".into()).field_all().cond(
+                Cond::new()
+                    .left("age")
+                    .op(surrealdb::sql::Operator::MoreThan)
+                    .right(18.into()),
+            ),
+        );
+        let script = Stmt::transaction()
+            .push(Stmt::r#let("company", "SurrealDB"))
+            .push(adults.clone())
+            .push(Stmt::update().table(adults.param().into()).data(
+                UpdateData::set().push(SetField::new("company", None, Param::new("company"))),
+            ));
+        assert_eq!(
+            script.to_string().as_str(),
+            "BEGIN TRANSACTION;\nLET $company = 'SurrealDB';\nLET $adults = (SELECT * FROM person WHERE age > 18);\nUPDATE $adults SET company = $company;\nCOMMIT TRANSACTION;"
+        );
+    }
+    #[test]
+    fn test_if() {
+        let user = Param::new("user");
+        let script = Stmt::transaction()
+            .push(Stmt::r#let("user", Value::Thing(("user", "tobie").into())))
+            .push(
+                Stmt::r#if(
+                    Cond::new()
+                        .left(user.field("name"))
+                        .op(surrealdb::sql::Operator::Equal)
+                        .right(Value::None),
+                )
+                .then(Stmt::create().table(user.into()))
+                .r#else()
+                .then_value("exists"),
+            );
+        assert_eq!(
+            script.to_string().as_str(),
+            "BEGIN TRANSACTION;\nLET $user = user:tobie;\nIF $user.name = NONE { CREATE $user; } ELSE { 'exists' };\nCOMMIT TRANSACTION;"
+        );
+    }
+    #[test]
+    fn test_for() {
+        let person = ForStmt::subquery(
+            "person",
+            Stmt::select()
+                .table("person".into())
+                .fields(vec![Field::new("id")]),
+        );
+        let item = person.param();
+        let update = Stmt::update()
+            .table(item.clone().into())
+            .data(UpdateData::set().push(SetField::new("can_vote", None, true)));
+        let person = person
+            .push(
+                Stmt::r#if(
+                    Cond::new()
+                        .left(item.field("age"))
+                        .op(surrealdb::sql::Operator::LessThan)
+                        .right(18.into()),
+                )
+                .then(Stmt::r#continue()),
+            )
+            .push(update);
+        assert_eq!(
+            person.to_string().as_str(),
+            "FOR $person IN (SELECT id FROM person) {\nIF $person.age < 18 { CONTINUE; };\nUPDATE $person SET can_vote = true;\n}"
+        );
+    }
+    #[test]
+    fn test_break() {
+        assert_eq!(Stmt::r#break().to_string().as_str(), "BREAK");
+        assert_eq!(Stmt::r#continue().to_string().as_str(), "CONTINUE");
+    }
+    #[test]
+    fn test_return() {
+        let r#return = Stmt::r#return(Param::new("person").field("name"));
+        assert_eq!(r#return.to_string().as_str(), "RETURN $person.name");
+    }
+    #[test]
+    fn test_throw() {
+        let person = Param::new("person");
+        let ifelse = Stmt::r#if(
+            Cond::new()
+                .left_value(person.clone().into())
+                .op(surrealdb::sql::Operator::Equal)
+                .right(Value::None),
+        )
+        .then(Stmt::throw("person not found"))
+        .r#else()
+        .then(Stmt::r#return(person.field("name")));
+        assert_eq!(
+            ifelse.to_string().as_str(),
+            "IF $person = NONE { THROW 'person not found'; } ELSE { RETURN $person.name; }"
+        );
+    }
+    #[test]
+    fn test_sleep() {
+        let sleep = Stmt::sleep(Duration::from_millis(500));
+        assert_eq!(sleep.to_string().as_str(), "SLEEP 500ms");
+    }
+    #[test]
+    fn test_show_changes() {
+        let show = Stmt::show_changes("reading".into())
+            .since_versionstamp(1)
+            .limit(10);
+        assert_eq!(
+            show.to_string().as_str(),
+            "SHOW CHANGES FOR TABLE reading SINCE 1 LIMIT 10"
+        );
+    }
+    #[test]
+    fn test_subquery() {
+        let select = Stmt::select().table("person".into()).field_all().cond(
+            Cond::new()
+                .left("id")
+                .op(surrealdb::sql::Operator::Inside)
+                .right(
+                    Stmt::select()
+                        .table("vip".into())
+                        .fields(vec![Field::new("person")])
+                        .into(),
+                ),
+        );
+        assert_eq!(
+            select.to_string().as_str(),
+            "SELECT * FROM person WHERE id INSIDE (SELECT person FROM vip)"
+        );
+    }
+    #[test]
+    fn test_cond_macro() {
+        let select = Stmt::select()
+            .table("person".into())
+            .field_all()
+            .cond(crate::cond!(
+                age >= 18 && (role == "admin" || verified == true)
+            ));
+        assert_eq!(
+            select.to_string().as_str(),
+            "SELECT * FROM person WHERE age >= 18 AND (role = 'admin' OR verified = true)"
+        );
+    }
+    #[test]
+    fn test_cond_helpers() {
+        let select = Stmt::select().table("article".into()).field_all().cond(
+            Cond::field("tags")
+                .contains_any(vec!["rust", "surrealdb"])
+                .and(Cond::field("title").matches(Some(1), "builder")),
+        );
+        assert_eq!(
+            select.to_string().as_str(),
+            "SELECT * FROM article WHERE tags CONTAINSANY ['rust', 'surrealdb'] AND title @1@ 'builder'"
+        );
+    }
 }
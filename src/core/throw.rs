@@ -0,0 +1,51 @@
+use surrealdb::sql::{statements::ThrowStatement, Value};
+
+use super::StmtBridge;
+use crate::impl_stmt_bridge;
+
+/// ## create THROW statement
+/// The THROW statement can be used to throw an error in a place where something unexpected is happening.
+/// Execution of the query will be aborted and the error will be returned to the client.
+/// ```
+/// THROW @error;
+/// ```
+/// ### example
+/// ```
+/// let throw = ThrowStmt::new("Some error message");
+/// assert_eq!(throw.to_string().as_str(), "THROW 'Some error message'");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThrowStmt {
+    origin: ThrowStatement,
+}
+
+impl ThrowStmt {
+    /// ## create THROW statement
+    /// - error : the error, usually a string
+    pub fn new(error: impl Into<Value>) -> Self {
+        ThrowStmt {
+            origin: ThrowStatement {
+                error: error.into(),
+            },
+        }
+    }
+}
+
+impl ToString for ThrowStmt {
+    fn to_string(&self) -> String {
+        self.origin.to_string()
+    }
+}
+
+impl_stmt_bridge!(ThrowStmt, ThrowStatement, Throw);
+
+#[cfg(test)]
+mod test_throw_stmt {
+    use super::ThrowStmt;
+
+    #[test]
+    fn simple() {
+        let throw = ThrowStmt::new("Some error message");
+        assert_eq!(throw.to_string().as_str(), "THROW 'Some error message'");
+    }
+}
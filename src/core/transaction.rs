@@ -0,0 +1,184 @@
+use surrealdb::sql::{
+    statements::{BeginStatement, CancelStatement, CommitStatement},
+    Statement, Statements,
+};
+
+use super::{render_stmt, StmtBridge};
+
+/// ## create TRANSACTION
+/// Each statement within SurrealDB is run within its own transaction by default.
+/// Use TransactionStmt to group multiple statements into one atomic script:
+///
+/// `BEGIN TRANSACTION; ...; COMMIT TRANSACTION;`
+///
+/// When the transaction ends with `CANCEL TRANSACTION;` all the changes will be discarded,
+/// which can be used for dry-run rehearsals
+/// ### example
+/// ```
+/// let transaction = TransactionStmt::new()
+///     .push(
+///         Stmt::update()
+///             .table(("account", "one").into())
+///             .data(UpdateData::set().push(SetField::new("balance", Some(Operator::Inc), 300.0))),
+///     )
+///     .push(
+///         Stmt::update()
+///             .table(("account", "two").into())
+///             .data(UpdateData::set().push(SetField::new("balance", Some(Operator::Dec), 300.0))),
+///     );
+/// assert_eq!(
+///     transaction.to_string().as_str(),
+///     "BEGIN TRANSACTION;\nUPDATE account:one SET balance += 300f;\nUPDATE account:two SET balance -= 300f;\nCOMMIT TRANSACTION;"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionStmt {
+    /// always starts with BEGIN and ends with COMMIT | CANCEL
+    origin: Statements,
+}
+
+impl TransactionStmt {
+    pub fn new() -> Self {
+        TransactionStmt {
+            origin: Statements(vec![
+                Statement::Begin(BeginStatement),
+                Statement::Commit(CommitStatement),
+            ]),
+        }
+    }
+    /// ## push a statement into the transaction
+    /// any statement which implement StmtBridge can be pushed
+    pub fn push<S>(mut self, stmt: S) -> Self
+    where
+        S: StmtBridge + Into<Statement>,
+    {
+        let index = self.origin.0.len() - 1;
+        self.origin.0.insert(index, stmt.into());
+        self
+    }
+    /// ## end with COMMIT TRANSACTION
+    /// default
+    pub fn commit(self) -> Self {
+        self.end(Statement::Commit(CommitStatement))
+    }
+    /// ## end with CANCEL TRANSACTION
+    /// all changes will be rolled back
+    pub fn cancel(self) -> Self {
+        self.end(Statement::Cancel(CancelStatement))
+    }
+    /// is the transaction end with CANCEL
+    pub fn is_cancel(&self) -> bool {
+        matches!(self.origin.0.last(), Some(Statement::Cancel(_)))
+    }
+    /// statements in the transaction, without BEGIN and COMMIT | CANCEL
+    pub fn stmts(&self) -> &[Statement] {
+        let len = self.origin.0.len();
+        &self.origin.0[1..len - 1]
+    }
+    fn end(mut self, end: Statement) -> Self {
+        if let Some(last) = self.origin.0.last_mut() {
+            *last = end;
+        }
+        self
+    }
+}
+
+impl ToString for TransactionStmt {
+    fn to_string(&self) -> String {
+        self.origin
+            .iter()
+            .map(|stmt| format!("{};", render_stmt(stmt)))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl StmtBridge for TransactionStmt {
+    type OriginType = Statements;
+
+    fn to_origin(self) -> Self::OriginType {
+        self.origin
+    }
+    fn origin(&self) -> &Self::OriginType {
+        &self.origin
+    }
+}
+
+#[cfg(test)]
+mod test_transaction_stmt {
+    use surrealdb::sql::Operator;
+
+    use crate::core::{
+        sql::{CreateData, SetField, UpdateData},
+        Stmt,
+    };
+
+    use super::TransactionStmt;
+
+    #[test]
+    fn commit() {
+        let transaction =
+            TransactionStmt::new()
+                .push(Stmt::update().table(("account", "one").into()).data(
+                    UpdateData::set().push(SetField::new("balance", Some(Operator::Inc), 300.0)),
+                ))
+                .push(Stmt::update().table(("account", "two").into()).data(
+                    UpdateData::set().push(SetField::new("balance", Some(Operator::Dec), 300.0)),
+                ));
+        assert_eq!(
+            transaction.to_string().as_str(),
+            "BEGIN TRANSACTION;\nUPDATE account:one SET balance += 300f;\nUPDATE account:two SET balance -= 300f;\nCOMMIT TRANSACTION;"
+        );
+        assert_eq!(transaction.stmts().len(), 2);
+    }
+
+    #[test]
+    fn cancel() {
+        let transaction = TransactionStmt::new()
+            .push(
+                Stmt::create()
+                    .table(("person", "tobie").into())
+                    .data(CreateData::set().push(SetField::new("name", None, "Tobie"))),
+            )
+            .push(Stmt::delete().table(("person", "jaime").into()))
+            .cancel();
+        assert!(transaction.is_cancel());
+        assert_eq!(
+            transaction.to_string().as_str(),
+            "BEGIN TRANSACTION;\nCREATE person:tobie SET name = 'Tobie';\nDELETE person:jaime;\nCANCEL TRANSACTION;"
+        );
+        assert!(!transaction.commit().is_cancel());
+    }
+
+    #[test]
+    fn empty() {
+        let transaction = TransactionStmt::new();
+        assert!(transaction.stmts().is_empty());
+        assert_eq!(
+            transaction.to_string().as_str(),
+            "BEGIN TRANSACTION;\nCOMMIT TRANSACTION;"
+        );
+    }
+
+    #[test]
+    fn remove_param() {
+        let transaction = TransactionStmt::new()
+            .push(Stmt::remove().param("limit"))
+            .push(Stmt::remove().table("temp".into()));
+        assert_eq!(
+            transaction.to_string().as_str(),
+            "BEGIN TRANSACTION;\nREMOVE PARAM $limit;\nREMOVE TABLE temp;\nCOMMIT TRANSACTION;"
+        );
+    }
+
+    #[test]
+    fn live_diff() {
+        let transaction = TransactionStmt::new()
+            .push(Stmt::live_select().table("person".into()).diff())
+            .push(Stmt::live_select().table("post".into()));
+        assert_eq!(
+            transaction.to_string().as_str(),
+            "BEGIN TRANSACTION;\nLIVE SELECT DIFF FROM person;\nLIVE SELECT * FROM post;\nCOMMIT TRANSACTION;"
+        );
+    }
+}
@@ -123,17 +123,34 @@ impl ToString for UpdateStmt {
     }
 }
 
-impl_stmt_bridge!(UpdateStmt, UpdateStatement);
+impl_stmt_bridge!(UpdateStmt, UpdateStatement, Update);
 
 #[cfg(test)]
 mod test_update_stmt {
     use serde::Serialize;
-    use surrealdb::sql::Operator;
+    use surrealdb::sql::{Dir, Operator};
 
-    use crate::core::sql::{PatchOp, SetField, SurrealTable, UpdateData};
+    use crate::core::sql::{Cond, Edges, PatchOp, SetField, SurrealTable, UpdateData};
 
     use super::UpdateStmt;
 
+    #[test]
+    fn graph_cond() {
+        let update = UpdateStmt::new()
+            .table("person".into())
+            .data(UpdateData::set().push(SetField::new("influencer", None, true)))
+            .cond(
+                Cond::new()
+                    .left(Edges::new("".into(), Dir::In, "follows".into()))
+                    .op(Operator::Contain)
+                    .right(surrealdb::sql::Thing::from(("person", "tobie")).into()),
+            );
+        assert_eq!(
+            update.to_string().as_str(),
+            "UPDATE person SET influencer = true WHERE <-follows CONTAINS person:tobie"
+        );
+    }
+
     #[test]
     fn patch() {
         let update = UpdateStmt::new()
@@ -28,7 +28,7 @@ impl UseStmt {
     }
 }
 
-impl_stmt_bridge!(UseStmt, UseStatement);
+impl_stmt_bridge!(UseStmt, UseStatement, Use);
 
 impl ToString for UseStmt {
     fn to_string(&self) -> String {
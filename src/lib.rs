@@ -73,3 +73,7 @@
 pub mod config;
 /// core mod for surreal_use
 pub mod core;
+
+/// surrealdb used by exported macros, such as `cond!`
+#[doc(hidden)]
+pub use surrealdb as __surrealdb;